@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The known answer for one part of a day, for one particular input.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: Number,
+}
+
+impl Entry {
+    fn is_for(&self, day: u8, part: u8, input_hash: &str) -> bool {
+        self.day == day && self.part == part && self.input_hash == input_hash
+    }
+}
+
+/// How an answer compares with the one recorded for the same input.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Number },
+    Unknown,
+}
+
+/// A set of recorded answers, stored as a JSON array of entries.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    entries: Vec<Entry>,
+}
+
+impl Manifest {
+    /// The answers manifest checked in at the root of the repository.
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.json"))
+    }
+
+    /// Reads a manifest, treating a missing file as one with no answers.
+    pub fn load(path: &Path) -> io::Result<Manifest> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Manifest {
+                entries: serde_json::from_str(&contents)?,
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(&self.entries)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+
+    pub fn check(&self, day: u8, part: u8, input_hash: &str, answer: &Number) -> Verdict {
+        let recorded = self
+            .entries
+            .iter()
+            .find(|entry| entry.is_for(day, part, input_hash));
+        match recorded {
+            Some(entry) if entry.answer == *answer => Verdict::Pass,
+            Some(entry) => Verdict::Fail {
+                expected: entry.answer.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Adds an answer, replacing any already recorded for the same input.
+    pub fn record(&mut self, entry: Entry) {
+        let existing = self
+            .entries
+            .iter()
+            .position(|existing| existing.is_for(entry.day, entry.part, &entry.input_hash));
+        match existing {
+            Some(index) => self.entries[index] = entry,
+            None => self.entries.push(entry),
+        }
+        // Keep the file ordered so that re-recording gives small diffs
+        self.entries
+            .sort_by(|a, b| (a.day, a.part, &a.input_hash).cmp(&(b.day, b.part, &b.input_hash)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(day: u8, part: u8, input_hash: &str, answer: u64) -> Entry {
+        Entry {
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            answer: answer.into(),
+        }
+    }
+
+    #[test]
+    fn it_checks_answers_against_the_same_input() {
+        let mut manifest = Manifest::default();
+        manifest.record(entry(7, 1, "abc", 6440));
+
+        assert_eq!(manifest.check(7, 1, "abc", &6440.into()), Verdict::Pass);
+        assert_eq!(
+            manifest.check(7, 1, "abc", &6441.into()),
+            Verdict::Fail {
+                expected: 6440.into()
+            }
+        );
+        assert_eq!(manifest.check(7, 1, "def", &6440.into()), Verdict::Unknown);
+        assert_eq!(manifest.check(7, 2, "abc", &6440.into()), Verdict::Unknown);
+    }
+
+    #[test]
+    fn it_replaces_answers_when_recording_the_same_input_again() {
+        let mut manifest = Manifest::default();
+        manifest.record(entry(9, 2, "abc", 1));
+        manifest.record(entry(7, 1, "abc", 2));
+        manifest.record(entry(9, 2, "abc", 3));
+
+        assert_eq!(
+            manifest.entries,
+            vec![entry(7, 1, "abc", 2), entry(9, 2, "abc", 3)]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use serde::Serialize;
+use std::time::Duration;
+
+/// How long one run of a solution spent parsing its input and solving it.
+#[derive(Clone, Copy, Debug)]
+pub struct Sample {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Summary statistics over a set of timings, in nanoseconds.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Statistics {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Statistics {
+    pub fn from_durations(durations: impl IntoIterator<Item = Duration>) -> Statistics {
+        let mut nanos: Vec<f64> = durations
+            .into_iter()
+            .map(|duration| duration.as_nanos() as f64)
+            .collect();
+        assert!(
+            !nanos.is_empty(),
+            "Cannot summarise an empty set of timings"
+        );
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len();
+        let median = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        // Sample standard deviation, since the runs are a sample of how the
+        // solution could perform rather than every run it will ever make
+        let stddev = match count {
+            1 => 0.0,
+            _ => {
+                let squared_deviations: f64 = nanos.iter().map(|x| (x - mean).powi(2)).sum();
+                (squared_deviations / (count - 1) as f64).sqrt()
+            }
+        };
+
+        Statistics {
+            min: nanos[0],
+            median,
+            mean,
+            stddev,
+        }
+    }
+}
+
+impl std::fmt::Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let show = |nanos: f64| Duration::from_nanos(nanos.round() as u64);
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            show(self.min),
+            show(self.median),
+            show(self.mean),
+            show(self.stddev)
+        )
+    }
+}
+
+/// The timings of every run of one solution.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    pub iterations: usize,
+    pub parse: Statistics,
+    pub solve: Statistics,
+}
+
+impl Report {
+    pub const CSV_HEADER: &'static str = "day,part,variant,iterations,\
+        parse_min,parse_median,parse_mean,parse_stddev,\
+        solve_min,solve_median,solve_mean,solve_stddev";
+
+    pub fn new(day: u8, part: u8, variant: Option<&'static str>, samples: &[Sample]) -> Report {
+        Report {
+            day,
+            part,
+            variant,
+            iterations: samples.len(),
+            parse: Statistics::from_durations(samples.iter().map(|sample| sample.parse)),
+            solve: Statistics::from_durations(samples.iter().map(|sample| sample.solve)),
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.variant.unwrap_or(""),
+            self.iterations,
+            self.parse.min,
+            self.parse.median,
+            self.parse.mean,
+            self.parse.stddev,
+            self.solve.min,
+            self.solve.median,
+            self.solve.mean,
+            self.solve.stddev
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|&value| Duration::from_millis(value))
+            .collect()
+    }
+
+    #[test]
+    fn it_summarises_an_odd_number_of_timings() {
+        let statistics = Statistics::from_durations(millis(&[5, 1, 3]));
+        assert_eq!(statistics.min, 1e6);
+        assert_eq!(statistics.median, 3e6);
+        assert_eq!(statistics.mean, 3e6);
+        assert_eq!(statistics.stddev, 2e6);
+    }
+
+    #[test]
+    fn it_takes_the_middle_pair_as_the_median_of_an_even_number_of_timings() {
+        let statistics = Statistics::from_durations(millis(&[4, 1, 2, 8]));
+        assert_eq!(statistics.median, 3e6);
+    }
+
+    #[test]
+    fn it_reports_no_deviation_for_a_single_timing() {
+        let statistics = Statistics::from_durations(millis(&[7]));
+        assert_eq!(
+            statistics,
+            Statistics {
+                min: 7e6,
+                median: 7e6,
+                mean: 7e6,
+                stddev: 0.0,
+            }
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use crate::output::Format;
+use crate::{read_input, solutions};
+use aoc_common::Input;
+use clap::{Args, Subcommand};
+use day_7::camel_cards::{self, Decider, Game, HandType, RankedHand, Showdown, Standing, Tally};
+use day_7::odds::Distribution;
+use day_7::rules::{RuleSet, RULE_SETS};
+use serde::Serialize;
+use std::fs;
+use std::io::BufRead;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Rank every hand in a game and total up the winnings
+    Rank(RankOptions),
+    /// Total up the winnings of a game while reading it a hand at a time, so
+    /// that memory use does not grow with the number of hands
+    Total(GameOptions),
+    /// Count how many hands in a game make each type
+    Histogram(HistogramOptions),
+    /// Work out the exact chance of a random hand making each type
+    Odds(OddsOptions),
+    /// Play two or more hands against each other and say what settles each
+    /// comparison
+    Compare(CompareOptions),
+}
+
+#[derive(Args)]
+pub struct GameOptions {
+    /// The rules to play by: `standard`, `jokers-wild`, or the path of a
+    /// rules file setting the cards, their order and which are wild
+    #[arg(long, default_value = "standard", value_parser = parse_rules)]
+    rules: RuleSet,
+
+    /// Read the hands from this file, or from stdin when given `-`, instead
+    /// of day 7's checked-in input.txt
+    #[arg(short, long)]
+    input: Option<Input>,
+}
+
+#[derive(Args)]
+pub struct RankOptions {
+    #[command(flatten)]
+    game: GameOptions,
+
+    /// Print every hand with how its type was worked out, its rank and what
+    /// it won
+    #[arg(long)]
+    explain: bool,
+
+    /// How to print the ranking. JSON and CSV list every hand rather than
+    /// just the total winnings
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+pub struct HistogramOptions {
+    #[command(flatten)]
+    game: GameOptions,
+
+    /// How to print the counts
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+pub struct OddsOptions {
+    /// The rules to play by: `standard`, `jokers-wild`, or the path of a
+    /// rules file setting the cards, their order and which are wild
+    #[arg(long, default_value = "standard", value_parser = parse_rules)]
+    rules: RuleSet,
+
+    /// How many cards a hand holds
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=16))]
+    cards: u8,
+
+    /// Cards known to be in the hand, in any order, e.g. "KK J". The rest
+    /// of the hand is dealt at random
+    #[arg(long, default_value = "")]
+    given: String,
+
+    /// How to print the odds
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+pub struct CompareOptions {
+    /// The rules to play by: `standard`, `jokers-wild`, or the path of a
+    /// rules file setting the cards, their order and which are wild
+    #[arg(long, default_value = "standard", value_parser = parse_rules)]
+    rules: RuleSet,
+
+    /// The cards of each hand, e.g. `KTJJT`
+    #[arg(required = true, num_args = 2..)]
+    hands: Vec<String>,
+}
+
+/// One hand's row in an exported ranking.
+#[derive(Debug, Serialize)]
+struct Row {
+    rank: u64,
+    hand: String,
+    #[serde(rename = "type")]
+    hand_type: String,
+    bid: u32,
+    winnings: u128,
+}
+
+impl Row {
+    const CSV_HEADER: &'static str = "rank,hand,type,bid,winnings";
+
+    fn new(hand: &RankedHand) -> Row {
+        Row {
+            rank: hand.rank,
+            hand: hand.cards().to_string(),
+            hand_type: format!("{:?}", hand.hand_type()),
+            bid: hand.bid(),
+            winnings: hand.winnings(),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.rank, self.hand, self.hand_type, self.bid, self.winnings
+        )
+    }
+}
+
+/// A whole exported ranking.
+#[derive(Debug, Serialize)]
+struct Ranking {
+    rules: String,
+    total_winnings: u128,
+    hands: Vec<Row>,
+}
+
+/// How many hands made one type, for an exported histogram.
+#[derive(Debug, Serialize)]
+struct TypeCount {
+    #[serde(rename = "type")]
+    hand_type: String,
+    hands: usize,
+}
+
+impl TypeCount {
+    const CSV_HEADER: &'static str = "type,hands";
+
+    fn new(hand_type: HandType, hands: usize) -> TypeCount {
+        TypeCount {
+            hand_type: format!("{:?}", hand_type),
+            hands,
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        format!("{},{}", self.hand_type, self.hands)
+    }
+}
+
+/// Reads the rules named by `--rules`, which are either built in or the
+/// contents of a rules file.
+fn parse_rules(value: &str) -> Result<RuleSet, String> {
+    if let Some(rules) = RuleSet::named(value) {
+        return Ok(rules.clone());
+    }
+    let contents = fs::read_to_string(value).map_err(|error| {
+        let names: Vec<&str> = RULE_SETS.iter().map(|rules| &*rules.name).collect();
+        format!(
+            "not one of {} or a readable rules file ({})",
+            names.join(", "),
+            error
+        )
+    })?;
+    RuleSet::parse(&contents).map_err(|error| format!("\n{}", error.render(&contents)))
+}
+
+/// The chance of one type, for exported odds.
+#[derive(Debug, Serialize)]
+struct TypeOdds {
+    #[serde(rename = "type")]
+    hand_type: String,
+    deals: u64,
+    total: u64,
+    probability: f64,
+}
+
+impl TypeOdds {
+    const CSV_HEADER: &'static str = "type,deals,total,probability";
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.hand_type, self.deals, self.total, self.probability
+        )
+    }
+}
+
+fn read_game(options: &GameOptions) -> Game {
+    let input = read_input(
+        options
+            .input
+            .as_ref()
+            .unwrap_or(&solutions::default_input(7)),
+    );
+    match Game::parse(&input, &options.rules) {
+        Ok(game) => game,
+        Err(error) => {
+            eprintln!("{}", error.render(&input));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints one row per hand, weakest first, showing the type the hand makes
+/// on its own, the card any jokers stood in for and the type that made.
+fn explain(ranked: &[RankedHand]) {
+    let hand_width = ranked
+        .iter()
+        .map(|hand| hand.cards().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("hand".len());
+    println!(
+        "{:>6}  {:<hand_width$}  {:>5}  {:>10}  type",
+        "rank", "hand", "bid", "winnings"
+    );
+    for hand in ranked {
+        println!(
+            "{:>6}  {:<hand_width$}  {:>5}  {:>10}  {}",
+            hand.rank,
+            hand.cards(),
+            hand.bid(),
+            hand.winnings(),
+            hand.explanation()
+        );
+    }
+}
+
+fn rank(options: &RankOptions) {
+    let ranked = read_game(&options.game).ranked();
+    let total_winnings = ranked.iter().map(RankedHand::winnings).sum();
+
+    match options.format {
+        Format::Text => {
+            if options.explain {
+                explain(&ranked);
+            }
+            println!("{}", day_7::part1::describe(total_winnings));
+        }
+        Format::Json => {
+            let ranking = Ranking {
+                rules: options.game.rules.name.to_string(),
+                total_winnings,
+                hands: ranked.iter().map(Row::new).collect(),
+            };
+            println!("{}", serde_json::to_string_pretty(&ranking).unwrap());
+        }
+        Format::Csv => {
+            println!("{}", Row::CSV_HEADER);
+            for hand in &ranked {
+                println!("{}", Row::new(hand).to_csv());
+            }
+        }
+    }
+}
+
+fn total(options: &GameOptions) {
+    let input = options
+        .input
+        .clone()
+        .unwrap_or_else(|| solutions::default_input(7));
+    let fail = |message: String| -> ! {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    };
+    let reader = input
+        .open()
+        .unwrap_or_else(|error| fail(format!("Failed to read {}: {}", input, error)));
+
+    let mut tally = Tally::new(&options.rules);
+    for line in reader.lines() {
+        let line =
+            line.unwrap_or_else(|error| fail(format!("Failed to read {}: {}", input, error)));
+        if let Err(error) = tally.add(&line) {
+            fail(error.render_line(&line));
+        }
+    }
+    println!("{}", day_7::part1::describe(tally.total_winnings()));
+}
+
+fn histogram(options: &HistogramOptions) {
+    let ranked = read_game(&options.game).ranked();
+    let counts = camel_cards::type_counts(&ranked);
+
+    match options.format {
+        Format::Text => {
+            for (hand_type, hands) in counts {
+                println!("{:<15}  {:>6}", hand_type, hands);
+            }
+        }
+        Format::Json => {
+            let counts: Vec<TypeCount> = counts
+                .into_iter()
+                .map(|(hand_type, hands)| TypeCount::new(hand_type, hands))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&counts).unwrap());
+        }
+        Format::Csv => {
+            println!("{}", TypeCount::CSV_HEADER);
+            for (hand_type, hands) in counts {
+                println!("{}", TypeCount::new(hand_type, hands).to_csv());
+            }
+        }
+    }
+}
+
+fn odds(options: &OddsOptions) {
+    let distribution = Distribution::of(&options.rules, options.cards as usize, &options.given)
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error.render(&options.given));
+            std::process::exit(1);
+        });
+    let odds: Vec<TypeOdds> = distribution
+        .counts
+        .iter()
+        .map(|&(hand_type, deals)| TypeOdds {
+            hand_type: format!("{:?}", hand_type),
+            deals,
+            total: distribution.total,
+            probability: distribution.probability(hand_type),
+        })
+        .collect();
+
+    match options.format {
+        Format::Text => {
+            let deals_width = distribution.total.to_string().len();
+            for (&(hand_type, _), odds) in distribution.counts.iter().zip(&odds) {
+                println!(
+                    "{:<15}  {:>deals_width$} / {}  {:>8.4}%",
+                    hand_type,
+                    odds.deals,
+                    odds.total,
+                    odds.probability * 100.0
+                );
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&odds).unwrap()),
+        Format::Csv => {
+            println!("{}", TypeOdds::CSV_HEADER);
+            for odds in &odds {
+                println!("{}", odds.to_csv());
+            }
+        }
+    }
+}
+
+fn compare(options: &CompareOptions) {
+    let hands: Vec<&str> = options.hands.iter().map(String::as_str).collect();
+    let standings = Showdown::parse(&hands, &options.rules)
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error.render(&hands.join("\n")));
+            std::process::exit(1);
+        })
+        .standings();
+
+    for pair in standings.windows(2) {
+        let (stronger, weaker) = (&pair[0], &pair[1]);
+        let card =
+            |standing: &Standing, position: usize| standing.cards.chars().nth(position).unwrap();
+        let (verdict, reason) = match stronger.over_next.unwrap() {
+            Decider::Type => ("beats", " on type".to_string()),
+            Decider::Card(position) => (
+                "beats",
+                format!(
+                    " on card {}, {} over {}",
+                    position + 1,
+                    card(stronger, position),
+                    card(weaker, position)
+                ),
+            ),
+            Decider::Tie => ("ties with", String::new()),
+        };
+        println!(
+            "{} ({}) {} {} ({}){}",
+            stronger.cards, stronger.hand_type, verdict, weaker.cards, weaker.hand_type, reason
+        );
+    }
+}
+
+pub fn run(command: &Command) {
+    match command {
+        Command::Rank(options) => rank(options),
+        Command::Total(options) => total(options),
+        Command::Histogram(options) => histogram(options),
+        Command::Odds(options) => odds(options),
+        Command::Compare(options) => compare(options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day_7::rules::JOKERS_WILD;
+
+    #[test]
+    fn it_exports_a_row_per_ranked_hand() {
+        let ranked = Game::parse("KTJJT 220\n32T3K 765\n", &JOKERS_WILD)
+            .unwrap()
+            .ranked();
+        let rows: Vec<String> = ranked.iter().map(|hand| Row::new(hand).to_csv()).collect();
+        assert_eq!(
+            rows,
+            vec!["1,32T3K,OnePair,765,765", "2,KTJJT,FourOfAKind,220,440"]
+        );
+    }
+}
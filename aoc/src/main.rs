@@ -0,0 +1,344 @@
+mod answers;
+mod bench;
+mod camel_cards;
+mod oasis;
+mod output;
+mod solutions;
+
+use answers::{Entry, Manifest, Verdict};
+use aoc_common::{Input, Rng};
+use bench::Report;
+use clap::{Args, Parser, Subcommand};
+use output::{Format, Record};
+use solutions::{Solution, GENERATORS, SOLUTIONS};
+use std::path::PathBuf;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(about = "Runs the Rust solutions to advent of code 2023")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, one part, or every solution in sequence
+    Run(RunOptions),
+    /// Time repeated runs of the selected solutions
+    Bench(BenchOptions),
+    /// Check the selected solutions against a manifest of known answers
+    Verify(VerifyOptions),
+    /// Print a random, reproducible puzzle input for a day
+    Generate(GenerateOptions),
+    /// Play games of camel cards from day 7 under different rules
+    #[command(subcommand)]
+    CamelCards(camel_cards::Command),
+    /// Extrapolate the OASIS sequences from day 9
+    #[command(subcommand)]
+    Oasis(oasis::Command),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Only run this day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run an alternative implementation, e.g. `concurrent` or `closed-form`
+    /// for day 9
+    #[arg(long)]
+    variant: Option<String>,
+
+    /// Read the puzzle input from this file, or from stdin when given `-`,
+    /// instead of the day's checked-in input.txt
+    #[arg(short, long, requires = "day")]
+    input: Option<Input>,
+}
+
+#[derive(Args)]
+struct RunOptions {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchOptions {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How many timed runs to make of each solution
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// How to print the timings
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyOptions {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// The answers manifest to check against
+    #[arg(long, default_value_os_t = Manifest::default_path())]
+    answers: PathBuf,
+
+    /// Record the current answers in the manifest instead of checking them
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct GenerateOptions {
+    /// The day to make an input for
+    #[arg(short, long)]
+    day: u8,
+
+    /// The same seed always gives the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// How large an input to make, counted in hands, sequences, patterns
+    /// and so on depending on the day. Defaults to about the size of the
+    /// real puzzle inputs
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+impl Selection {
+    fn matches(&self, solution: &Solution) -> bool {
+        self.day.is_none_or(|day| day == solution.day)
+            && self.part.is_none_or(|part| part == solution.part)
+            && self.variant.as_deref() == solution.variant
+    }
+}
+
+fn read_input(input: &Input) -> String {
+    input.read().unwrap_or_else(|error| {
+        eprintln!("Failed to read {}: {}", input, error);
+        std::process::exit(1);
+    })
+}
+
+/// The selected solutions, each paired with the input it should be run on.
+fn load(selection: &Selection) -> Vec<(&'static Solution, String)> {
+    let selected: Vec<&Solution> = SOLUTIONS
+        .iter()
+        .filter(|solution| selection.matches(solution))
+        .collect();
+
+    if selected.is_empty() {
+        eprintln!("No solutions match the given selection");
+        std::process::exit(1);
+    }
+
+    // Stdin can only be consumed once, so an explicit input is read
+    // up front and shared by every selected part
+    let shared_input = selection.input.as_ref().map(read_input);
+
+    selected
+        .into_iter()
+        .map(|solution| {
+            let input = match &shared_input {
+                Some(input) => input.clone(),
+                None => read_input(&solutions::default_input(solution.day)),
+            };
+            (solution, input)
+        })
+        .collect()
+}
+
+fn run(options: &RunOptions) {
+    let mut records: Vec<Record> = Vec::new();
+
+    if let Format::Csv = options.format {
+        println!("{}", Record::CSV_HEADER);
+    }
+
+    for (solution, input) in load(&options.selection) {
+        if let Format::Text = options.format {
+            println!("{}", solution.name());
+        }
+
+        let start = Instant::now();
+        let answer = (solution.solve)(&input).unwrap_or_else(|error| {
+            eprintln!("{}", error.render(&input));
+            std::process::exit(1);
+        });
+        let elapsed = start.elapsed();
+
+        let record = Record {
+            day: solution.day,
+            part: solution.part,
+            variant: solution.variant,
+            answer: answer.value,
+            elapsed: elapsed.as_nanos(),
+            input_hash: output::input_hash(&input),
+        };
+        match options.format {
+            Format::Text => println!("{}", answer.description),
+            Format::Json => records.push(record),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+    }
+
+    if let Format::Json = options.format {
+        println!("{}", serde_json::to_string_pretty(&records).unwrap());
+    }
+}
+
+fn bench(options: &BenchOptions) {
+    let mut reports: Vec<Report> = Vec::new();
+
+    if let Format::Csv = options.format {
+        println!("{}", Report::CSV_HEADER);
+    }
+
+    for (solution, input) in load(&options.selection) {
+        let mut samples = Vec::new();
+        // An untimed first run warms up the caches and checks that the
+        // input parses before anything is measured
+        for _ in 0..=options.iterations {
+            match (solution.bench)(&input) {
+                Ok(sample) => samples.push(sample),
+                Err(error) => {
+                    eprintln!("{}", error.render(&input));
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        let report = Report::new(solution.day, solution.part, solution.variant, &samples[1..]);
+        match options.format {
+            Format::Text => {
+                println!("{} ({} runs)", solution.name(), report.iterations);
+                println!("  parse: {}", report.parse);
+                println!("  solve: {}", report.solve);
+            }
+            Format::Json => reports.push(report),
+            Format::Csv => println!("{}", report.to_csv()),
+        }
+    }
+
+    if let Format::Json = options.format {
+        println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+    }
+}
+
+fn verify(options: &VerifyOptions) {
+    let mut manifest = Manifest::load(&options.answers).unwrap_or_else(|error| {
+        eprintln!("Failed to read {}: {}", options.answers.display(), error);
+        std::process::exit(1);
+    });
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for (solution, input) in load(&options.selection) {
+        let input_hash = output::input_hash(&input);
+        let answer = match (solution.solve)(&input) {
+            Ok(answer) => answer,
+            Err(error) => {
+                println!("{}: error", solution.name());
+                eprintln!("{}", error.render(&input));
+                failed += 1;
+                continue;
+            }
+        };
+
+        if options.record {
+            println!("{}: recorded {}", solution.name(), answer.value);
+            manifest.record(Entry {
+                day: solution.day,
+                part: solution.part,
+                input_hash,
+                answer: answer.value,
+            });
+            continue;
+        }
+
+        match manifest.check(solution.day, solution.part, &input_hash, &answer.value) {
+            Verdict::Pass => {
+                println!("{}: pass", solution.name());
+                passed += 1;
+            }
+            Verdict::Fail { expected } => {
+                println!(
+                    "{}: FAIL, expected {} but got {}",
+                    solution.name(),
+                    expected,
+                    answer.value
+                );
+                failed += 1;
+            }
+            Verdict::Unknown => {
+                println!("{}: unknown, got {}", solution.name(), answer.value);
+                unknown += 1;
+            }
+        }
+    }
+
+    if options.record {
+        manifest.save(&options.answers).unwrap_or_else(|error| {
+            eprintln!("Failed to write {}: {}", options.answers.display(), error);
+            std::process::exit(1);
+        });
+    } else {
+        println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn generate(options: &GenerateOptions) {
+    let generator = GENERATORS
+        .iter()
+        .find(|generator| generator.day == options.day)
+        .unwrap_or_else(|| {
+            eprintln!("There is no input generator for day {}", options.day);
+            std::process::exit(1);
+        });
+
+    let mut rng = Rng::new(options.seed);
+    let size = options.size.unwrap_or(generator.default_size);
+    if !generator.sizes.contains(&size) {
+        match *generator.sizes.end() {
+            usize::MAX => eprintln!(
+                "A day {} input needs a size of at least {}",
+                options.day,
+                generator.sizes.start()
+            ),
+            end => eprintln!(
+                "A day {} input needs a size from {} to {}",
+                options.day,
+                generator.sizes.start(),
+                end
+            ),
+        }
+        std::process::exit(1);
+    }
+    println!("{}", (generator.generate)(&mut rng, size));
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(options) => run(&options),
+        Command::Bench(options) => bench(&options),
+        Command::Verify(options) => verify(&options),
+        Command::Generate(options) => generate(&options),
+        Command::CamelCards(command) => camel_cards::run(&command),
+        Command::Oasis(command) => oasis::run(&command),
+    }
+}
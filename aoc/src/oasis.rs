@@ -0,0 +1,293 @@
+use crate::output::Format;
+use crate::{read_input, solutions};
+use aoc_common::Input;
+use clap::{Args, Subcommand};
+use day_9::polynomial::Polynomial;
+use day_9::sequence::{DifferenceTable, SequenceError};
+use num_traits::Zero;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Predict readings past either end of every sequence
+    Predict(PredictOptions),
+    /// List the sequences too steep to extrapolate in 64 bit integers,
+    /// which are worked out in big integers instead
+    Overflows(OverflowsOptions),
+    /// Work out the polynomial behind every sequence and check that it
+    /// reproduces the readings
+    Fit(FitOptions),
+}
+
+#[derive(Args)]
+pub struct PredictOptions {
+    /// How many readings to predict after the end of each sequence
+    #[arg(long, default_value_t = 1)]
+    ahead: usize,
+
+    /// How many readings to predict before the start of each sequence
+    #[arg(long, default_value_t = 0)]
+    behind: usize,
+
+    /// Read the sequences from this file, or from stdin when given `-`,
+    /// instead of day 9's checked-in input.txt
+    #[arg(short, long)]
+    input: Option<Input>,
+}
+
+#[derive(Args)]
+pub struct OverflowsOptions {
+    /// Read the sequences from this file, or from stdin when given `-`,
+    /// instead of day 9's checked-in input.txt
+    #[arg(short, long)]
+    input: Option<Input>,
+}
+
+#[derive(Args)]
+pub struct FitOptions {
+    /// Read the sequences from this file, or from stdin when given `-`,
+    /// instead of day 9's checked-in input.txt
+    #[arg(short, long)]
+    input: Option<Input>,
+
+    /// How to print the fits
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// The polynomial behind one line, for the fit report. A line whose
+/// differences never become constant is fitted through all but its last
+/// reading instead, and reported along with the error and the readings that
+/// polynomial misses. A line with no readings has no polynomial at all.
+#[derive(Debug, Serialize)]
+struct Fit {
+    line: usize,
+    readings: usize,
+    /// How many readings, from the first, the polynomial was fitted through
+    fitted: usize,
+    degree: Option<usize>,
+    /// Exact fractions, from the constant term up
+    coefficients: Vec<String>,
+    polynomial: Option<String>,
+    /// The `x` of each reading the polynomial does not reproduce
+    misses: Vec<usize>,
+    error: Option<String>,
+}
+
+impl Fit {
+    const CSV_HEADER: &'static str =
+        "line,readings,fitted,degree,coefficients,polynomial,misses,error";
+
+    fn new(line_number: usize, values: &[i64]) -> Fit {
+        let mut fit = Fit {
+            line: line_number + 1,
+            readings: values.len(),
+            fitted: 0,
+            degree: None,
+            coefficients: Vec::new(),
+            polynomial: None,
+            misses: Vec::new(),
+            error: None,
+        };
+        let polynomial = match DifferenceTable::new(values) {
+            Ok(table) => Polynomial::fit(&table),
+            Err(error) => {
+                fit.error = Some(error.to_string());
+                match error {
+                    SequenceError::Empty => return fit,
+                    SequenceError::NeverConstant => {
+                        Polynomial::through(&values[..values.len() - 1])
+                    }
+                }
+            }
+        };
+
+        fit.fitted = polynomial.degree() + 1;
+        fit.degree = Some(polynomial.degree());
+        fit.coefficients = polynomial
+            .coefficients()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
+        fit.misses = polynomial
+            .residuals(values)
+            .iter()
+            .enumerate()
+            .filter(|(_, residual)| !residual.is_zero())
+            .map(|(x, _)| x)
+            .collect();
+        fit.polynomial = Some(polynomial.to_string());
+        fit
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.line,
+            self.readings,
+            self.fitted,
+            self.degree
+                .map(|degree| degree.to_string())
+                .unwrap_or_default(),
+            self.coefficients.join(" "),
+            self.polynomial.as_deref().unwrap_or(""),
+            join(self.misses.iter()),
+            self.error.as_deref().unwrap_or("")
+        )
+    }
+}
+
+impl fmt::Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        if let Some(error) = &self.error {
+            write!(f, "{}", error)?;
+        }
+        let (Some(polynomial), Some(degree)) = (&self.polynomial, self.degree) else {
+            return Ok(());
+        };
+        if self.error.is_some() {
+            write!(f, ", so fitted the first {} readings: ", self.fitted)?;
+        }
+        write!(f, "degree {}, f(x) = {}, ", degree, polynomial)?;
+        if self.misses.is_empty() {
+            write!(f, "reproduces all {} readings", self.readings)
+        } else {
+            write!(f, "misses the readings at x = {}", join(self.misses.iter()))
+        }
+    }
+}
+
+/// Reads every sequence of readings, one per line, into its table of
+/// differences.
+fn read_sequences(input: &Option<Input>) -> Vec<DifferenceTable> {
+    let input = read_input(input.as_ref().unwrap_or(&solutions::default_input(9)));
+    day_9::parse_sequences(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&input));
+        std::process::exit(1);
+    })
+}
+
+fn join(values: impl Iterator<Item = impl ToString>) -> String {
+    values
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Prints the predictions for each line in reading order, so the readings
+/// before a sequence are listed from the furthest to the nearest.
+fn predict(options: &PredictOptions) {
+    for (line_number, table) in read_sequences(&options.input).iter().enumerate() {
+        let mut predictions = format!("line {}:", line_number + 1);
+        if options.behind > 0 {
+            let before = table.backward(options.behind);
+            predictions += &format!(" before {}", join(before.into_iter().rev()));
+        }
+        if options.ahead > 0 {
+            let after = table.forward(options.ahead);
+            predictions += &format!(" after {}", join(after.into_iter()));
+        }
+        println!("{}", predictions);
+    }
+}
+
+fn overflows(options: &OverflowsOptions) {
+    let sequences = read_sequences(&options.input);
+    let mut overflowing = 0;
+    for (line_number, table) in sequences.iter().enumerate() {
+        if table.needs_big_integers() {
+            println!("line {}: needs big integers", line_number + 1);
+            overflowing += 1;
+        }
+    }
+    println!(
+        "{} of {} sequences needed big integers",
+        overflowing,
+        sequences.len()
+    );
+}
+
+/// Fits every line separately, so that a line which is not a polynomial is
+/// reported along with the rest rather than stopping the report.
+fn fit(options: &FitOptions) {
+    let input = read_input(
+        options
+            .input
+            .as_ref()
+            .unwrap_or(&solutions::default_input(9)),
+    );
+    let fits: Vec<Fit> = input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| {
+            let values = day_9::parse_values::<i64>(line, line_number).unwrap_or_else(|error| {
+                eprintln!("{}", error.render(&input));
+                std::process::exit(1);
+            });
+            Fit::new(line_number, &values)
+        })
+        .collect();
+
+    match options.format {
+        Format::Text => {
+            for fit in &fits {
+                println!("{}", fit);
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&fits).unwrap()),
+        Format::Csv => {
+            println!("{}", Fit::CSV_HEADER);
+            for fit in &fits {
+                println!("{}", fit.to_csv());
+            }
+        }
+    }
+}
+
+pub fn run(command: &Command) {
+    match command {
+        Command::Predict(options) => predict(options),
+        Command::Overflows(options) => overflows(options),
+        Command::Fit(options) => fit(options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_fits_a_polynomial_sequence() {
+        let fit = Fit::new(0, &[1, 3, 6, 10, 15, 21]);
+        assert_eq!(
+            fit.to_string(),
+            "line 1: degree 2, f(x) = 1/2 x^2 + 3/2 x + 1, reproduces all 6 readings"
+        );
+        assert_eq!(fit.to_csv(), "1,6,3,2,1 3/2 1/2,1/2 x^2 + 3/2 x + 1,,");
+    }
+
+    #[test]
+    fn it_shows_which_readings_a_sequence_misses() {
+        let fit = Fit::new(2, &[1, 2, 4, 8, 16]);
+        assert_eq!(
+            fit.to_string(),
+            "line 3: The differences never become constant, so fitted the first 4 readings: \
+             degree 3, f(x) = 1/6 x^3 + 5/6 x + 1, misses the readings at x = 4"
+        );
+        assert_eq!(fit.coefficients, ["1", "5/6", "0", "1/6"]);
+        assert_eq!(
+            fit.to_csv(),
+            "3,5,4,3,1 5/6 0 1/6,1/6 x^3 + 5/6 x + 1,4,The differences never become constant"
+        );
+    }
+
+    #[test]
+    fn it_reports_a_line_without_readings() {
+        assert_eq!(
+            Fit::new(0, &[]).to_string(),
+            "line 1: Expected at least one reading"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Number;
+use sha2::{Digest, Sha256};
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Human readable sentences
+    Text,
+    /// A JSON array with one object per solution
+    Json,
+    /// A header row followed by one row per solution
+    Csv,
+}
+
+/// The outcome of running one solution, for machine readable output.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    pub answer: Number,
+    /// Wall clock time spent solving, in nanoseconds
+    pub elapsed: u128,
+    pub input_hash: String,
+}
+
+impl Record {
+    pub const CSV_HEADER: &'static str = "day,part,variant,answer,elapsed,input_hash";
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.variant.unwrap_or(""),
+            self.answer,
+            self.elapsed,
+            self.input_hash
+        )
+    }
+}
+
+/// The hex encoded SHA-256 digest of a puzzle input, which identifies it
+/// without having to publish the input itself.
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hashes_inputs_with_sha256() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn it_leaves_the_variant_column_empty_when_there_is_none() {
+        let record = Record {
+            day: 7,
+            part: 1,
+            variant: None,
+            answer: 6440.into(),
+            elapsed: 1500,
+            input_hash: "abc".to_string(),
+        };
+        assert_eq!(record.to_csv(), "7,1,,6440,1500,abc");
+    }
+}
@@ -0,0 +1,142 @@
+use crate::bench::Sample;
+use aoc_common::{Input, ParseError, Rng};
+use serde_json::Number;
+use std::fmt;
+use std::hint::black_box;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// What a solution found, as a bare value and as the sentence describing it.
+pub struct Answer {
+    pub value: Number,
+    pub description: String,
+}
+
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<&'static str>,
+    pub solve: fn(&str) -> Result<Answer, ParseError>,
+    /// Solves the input once, timing the parse and the solve separately.
+    pub bench: fn(&str) -> Result<Sample, ParseError>,
+}
+
+impl Solution {
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("Day {}, part {} ({})", self.day, self.part, variant),
+            None => format!("Day {}, part {}", self.day, self.part),
+        }
+    }
+}
+
+/// Answers are whole numbers of whatever size their day needs, up to big
+/// integers, so they are carried over through their decimal digits, which
+/// `serde_json` keeps exactly.
+fn to_number(answer: &impl fmt::Display) -> Number {
+    answer
+        .to_string()
+        .parse()
+        .expect("Answers are whole numbers")
+}
+
+/// Builds a day's `Solution` from its part module. A module whose
+/// `solve_puzzle` can fail is marked with a trailing `?`.
+macro_rules! solution {
+    ($day:literal, $part:literal, $($module:ident)::+ ?) => {
+        solution!(@build $day, $part, None, [?] $($module)::+)
+    };
+    ($day:literal, $part:literal, $($module:ident)::+) => {
+        solution!(@build $day, $part, None, [] $($module)::+)
+    };
+    ($day:literal, $part:literal, $variant:literal, $($module:ident)::+) => {
+        solution!(@build $day, $part, Some($variant), [] $($module)::+)
+    };
+    (@build $day:literal, $part:literal, $variant:expr, [$($try:tt)?] $($module:ident)::+) => {
+        Solution {
+            day: $day,
+            part: $part,
+            variant: $variant,
+            solve: |input| {
+                $($module)::+::solve(input).map(|answer| Answer {
+                    value: to_number(&answer),
+                    description: $($module)::+::describe(answer),
+                })
+            },
+            bench: |input| {
+                let start = Instant::now();
+                let puzzle = black_box($($module)::+::parse(black_box(input))?);
+                let parsed = Instant::now();
+                black_box($($module)::+::solve_puzzle(puzzle)$($try)?);
+                Ok(Sample {
+                    parse: parsed - start,
+                    solve: parsed.elapsed(),
+                })
+            },
+        }
+    };
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(7, 1, day_7::part1),
+    solution!(7, 2, day_7::part2),
+    solution!(9, 1, day_9::part1),
+    solution!(9, 1, "concurrent", day_9::part1_concurrent),
+    solution!(9, 1, "closed-form", day_9::part1_closed_form),
+    solution!(9, 2, day_9::part2),
+    solution!(9, 2, "closed-form", day_9::part2_closed_form),
+    solution!(10, 1, day_10::part1),
+    solution!(10, 2, day_10::part2),
+    solution!(11, 1, day_11::part1),
+    solution!(11, 2, day_11::part2),
+    solution!(12, 1, day_12::part1),
+    solution!(12, 2, day_12::part2),
+    solution!(13, 1, day_13::part1?),
+    solution!(13, 2, day_13::part2?),
+    solution!(14, 1, day_14::part1),
+    solution!(14, 2, day_14::part2),
+    solution!(15, 1, day_15::part1),
+    solution!(15, 2, day_15::part2),
+];
+
+/// Makes random puzzle inputs for a day.
+pub struct Generator {
+    pub day: u8,
+    /// A size that gives inputs about as large as the real ones
+    pub default_size: usize,
+    /// The sizes it can make an input of
+    pub sizes: RangeInclusive<usize>,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+macro_rules! generator {
+    ($day:literal, $($module:ident)::+) => {
+        Generator {
+            day: $day,
+            default_size: $($module)::+::DEFAULT_SIZE,
+            sizes: $($module)::+::SIZES,
+            generate: $($module)::+::generate,
+        }
+    };
+}
+
+pub const GENERATORS: &[Generator] = &[
+    generator!(7, day_7::generate),
+    generator!(9, day_9::generate),
+    generator!(10, day_10::generate),
+    generator!(11, day_11::generate),
+    generator!(12, day_12::generate),
+    generator!(13, day_13::generate),
+    generator!(14, day_14::generate),
+    generator!(15, day_15::generate),
+];
+
+/// The checked-in puzzle input for a day.
+pub fn default_input(day: u8) -> Input {
+    Input::Path(PathBuf::from(format!(
+        "{}/../day_{}/input.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    )))
+}
@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+
+/// A problem with the puzzle input, located by its zero-based line and
+/// column (in characters) so that it can be pointed at in the source.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error from a byte offset into the whole source, for parsers
+    /// that do not work line by line.
+    pub fn at_offset(
+        source: &str,
+        offset: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> ParseError {
+        let before = &source[..offset];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count();
+        ParseError::new(line, column, text, message)
+    }
+
+    /// Formats the error with the offending line and a caret underneath the
+    /// offending text, e.g.
+    ///
+    /// ```text
+    /// error: Unexpected card `X`
+    ///  --> line 3, column 2
+    ///   |
+    /// 3 | 3XT3K 765
+    ///   |  ^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        self.render_line(source.lines().nth(self.line).unwrap_or(""))
+    }
+
+    /// Formats the error like `render`, given only the offending line, for
+    /// callers that read their input a line at a time.
+    pub fn render_line(&self, source_line: &str) -> String {
+        let line_number = (self.line + 1).to_string();
+        let gutter = " ".repeat(line_number.len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+
+        let mut rendered = format!("error: {}", self.message);
+        if !self.text.is_empty() {
+            rendered += &format!(" `{}`", self.text);
+        }
+        rendered += &format!(
+            "\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            gutter,
+            self.line + 1,
+            self.column + 1,
+            gutter,
+            line_number,
+            source_line,
+            gutter,
+            " ".repeat(self.column),
+            underline
+        );
+        rendered
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        write!(f, " at line {}, column {}", self.line + 1, self.column + 1)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Splits a line on whitespace, keeping the column each word starts at.
+pub fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |word| {
+        let offset = word.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count(), word)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_renders_a_caret_under_the_offending_text() {
+        let error = ParseError::new(2, 1, "X", "Unexpected card");
+        let source = "32T3K 765\nT55J5 684\n3XT3K 28\n";
+        assert_eq!(
+            error.render(source),
+            "error: Unexpected card `X`\n --> line 3, column 2\n  |\n3 | 3XT3K 28\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn it_underlines_the_whole_offending_text() {
+        let error = ParseError::new(0, 6, "76x", "Invalid bid");
+        assert!(error.render("32T3K 76x").ends_with("|       ^^^"));
+    }
+
+    #[test]
+    fn it_locates_byte_offsets() {
+        let error = ParseError::at_offset("rn=1,cm-\nqp=x", 12, "x", "Invalid focal length");
+        assert_eq!(error, ParseError::new(1, 3, "x", "Invalid focal length"));
+    }
+
+    #[test]
+    fn it_splits_words_with_columns() {
+        let words: Vec<(usize, &str)> = words("0  3 6 9").collect();
+        assert_eq!(words, vec![(0, "0"), (3, "3"), (5, "6"), (7, "9")]);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where a solver should read its puzzle input from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Uses the first command line argument if there is one, otherwise falls
+    /// back to the given path (usually the day's checked-in `input.txt`).
+    pub fn from_args_or(default_path: &str) -> Input {
+        match std::env::args().nth(1) {
+            Some(arg) => Input::from_arg(&arg),
+            None => Input::Path(PathBuf::from(default_path)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::Path(path) => fs::read_to_string(path),
+            Input::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            }
+            Input::Inline(contents) => Ok(contents.clone()),
+        }
+    }
+
+    /// Opens the input to be read a line at a time, for inputs too large to
+    /// read into memory all at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Input::Path(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::Inline(contents) => Ok(Box::new(Cursor::new(contents.clone().into_bytes()))),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input::from_arg(s))
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::Path(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_dash_as_stdin() {
+        assert_eq!(Input::from_arg("-"), Input::Stdin);
+    }
+
+    #[test]
+    fn it_parses_a_path() {
+        assert_eq!(
+            Input::from_arg("../input.txt"),
+            Input::Path(PathBuf::from("../input.txt"))
+        );
+    }
+
+    #[test]
+    fn it_reads_inline_input() {
+        let input = Input::Inline("32T3K 765\n".to_string());
+        assert_eq!(input.read().unwrap(), "32T3K 765\n");
+    }
+
+    #[test]
+    fn it_opens_inline_input_a_line_at_a_time() {
+        let input = Input::Inline("32T3K 765\nT55J5 684\n".to_string());
+        let lines: Vec<String> = input.open().unwrap().lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["32T3K 765", "T55J5 684"]);
+    }
+
+    #[test]
+    fn it_reports_missing_files() {
+        let input = Input::Path(PathBuf::from("does/not/exist.txt"));
+        assert!(input.read().is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod input;
+pub mod rng;
+
+pub use error::ParseError;
+pub use input::Input;
+pub use rng::Rng;
@@ -0,0 +1,82 @@
+/// A small seedable pseudo-random number generator (SplitMix64) for building
+/// synthetic puzzle inputs. Its output depends only on the seed, so the same
+/// seed gives the same input on every platform and with every release.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Cannot pick a number below zero");
+        // Taking the high half of the widened product avoids the bias that
+        // `next_u64() % bound` has towards small numbers
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "Cannot pick from an empty range");
+        low + self.below((high - low) as usize + 1) as i64
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(seed: u64) -> Vec<u64> {
+        let mut rng = Rng::new(seed);
+        (0..5).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn it_repeats_the_same_sequence_for_a_seed() {
+        assert_eq!(sequence(42), sequence(42));
+        assert_ne!(sequence(42), sequence(43));
+    }
+
+    #[test]
+    fn it_matches_the_reference_splitmix64_output() {
+        let mut rng = Rng::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+    }
+
+    #[test]
+    fn it_stays_within_bounds() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.between(-2, 2)));
+        }
+    }
+}
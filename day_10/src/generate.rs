@@ -0,0 +1,189 @@
+use aoc_common::Rng;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// Gives a 140 by 140 grid, the size of the real puzzle inputs.
+pub const DEFAULT_SIZE: usize = 46;
+
+/// The loop needs at least one node to run round.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+const NORTH: u8 = 1;
+const SOUTH: u8 = 2;
+const WEST: u8 = 4;
+const EAST: u8 = 8;
+
+fn pipe(connections: u8) -> char {
+    match connections {
+        c if c == NORTH | SOUTH => '|',
+        c if c == WEST | EAST => '-',
+        c if c == NORTH | EAST => 'L',
+        c if c == NORTH | WEST => 'J',
+        c if c == SOUTH | WEST => '7',
+        c if c == SOUTH | EAST => 'F',
+        _ => panic!("A pipe has exactly two connections"),
+    }
+}
+
+/// A grid of `3 * size + 2` by `3 * size + 2` tiles holding a single loop.
+///
+/// A random tree is grown over some of a `size` by `size` grid of nodes and
+/// drawn with every node three tiles from the next, and the loop runs round
+/// the tiles it covers. Those tiles end up inside the loop, and every tile
+/// off the loop is filled with ground or stray pipes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    assert!(SIZES.contains(&size), "The loop needs at least one node");
+
+    // Grow the tree by repeatedly joining a random node next to it
+    let target_nodes = (size * size * rng.between(30, 90) as usize).div_ceil(100);
+    let start = (rng.below(size), rng.below(size));
+    let mut in_tree: HashSet<(usize, usize)> = HashSet::from([start]);
+    let mut edges: Vec<((usize, usize), (usize, usize))> = Vec::new();
+    let mut frontier: Vec<((usize, usize), (usize, usize))> = Vec::new();
+    let add_frontier = |node: (usize, usize), frontier: &mut Vec<_>| {
+        let (x, y) = node;
+        if x > 0 {
+            frontier.push((node, (x - 1, y)));
+        }
+        if x + 1 < size {
+            frontier.push((node, (x + 1, y)));
+        }
+        if y > 0 {
+            frontier.push((node, (x, y - 1)));
+        }
+        if y + 1 < size {
+            frontier.push((node, (x, y + 1)));
+        }
+    };
+    add_frontier(start, &mut frontier);
+    while in_tree.len() < target_nodes && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.below(frontier.len()));
+        if in_tree.insert(to) {
+            edges.push((from, to));
+            add_frontier(to, &mut frontier);
+        }
+    }
+
+    // Draw the tree one tile wide, leaving a border of at least one tile
+    // around it for the loop
+    let width = 3 * size + 2;
+    let tile = |(x, y): (usize, usize)| (3 * x + 2, 3 * y + 2);
+    let mut tree = vec![vec![false; width]; width];
+    for &node in &in_tree {
+        let (x, y) = tile(node);
+        tree[y][x] = true;
+    }
+    for &(from, to) in &edges {
+        let ((x1, y1), (x2, y2)) = (tile(from), tile(to));
+        for row in &mut tree[y1.min(y2)..=y1.max(y2)] {
+            row[x1.min(x2)..=x1.max(x2)].fill(true);
+        }
+    }
+
+    // The loop covers every tile touching the tree, even diagonally. Two
+    // neighbouring loop tiles are joined when they touch the same tree tile,
+    // which keeps loop tiles either side of a gap between branches apart
+    let touching = |x: usize, y: usize| -> Vec<(usize, usize)> {
+        (y.saturating_sub(1)..=(y + 1).min(width - 1))
+            .flat_map(|ty| (x.saturating_sub(1)..=(x + 1).min(width - 1)).map(move |tx| (tx, ty)))
+            .filter(|&(tx, ty)| tree[ty][tx])
+            .collect()
+    };
+    let on_loop = |x: usize, y: usize| !tree[y][x] && !touching(x, y).is_empty();
+    let mut connections = vec![vec![0u8; width]; width];
+    // The loop never reaches the outermost tiles, so only the tiles inside
+    // them need checking, and they all have four neighbours
+    let inner_tiles = (1..width - 1).flat_map(|y| (1..width - 1).map(move |x| (x, y)));
+    for (x, y) in inner_tiles {
+        if !on_loop(x, y) {
+            continue;
+        }
+        let tree_tiles = touching(x, y);
+        let neighbours = [
+            (x, y - 1, NORTH),
+            (x, y + 1, SOUTH),
+            (x - 1, y, WEST),
+            (x + 1, y, EAST),
+        ];
+        for (nx, ny, direction) in neighbours {
+            if on_loop(nx, ny) && touching(nx, ny).iter().any(|t| tree_tiles.contains(t)) {
+                connections[y][x] |= direction;
+            }
+        }
+    }
+
+    const STRAYS: [char; 10] = ['.', '.', '.', '.', '|', '-', 'L', 'J', '7', 'F'];
+    let mut tiles: Vec<Vec<char>> = connections
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&c| {
+                    if c == 0 {
+                        *rng.choose(&STRAYS)
+                    } else {
+                        pipe(c)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    // Start somewhere on the loop, making sure no stray pipe next to the
+    // start looks as though it joins it
+    let loop_tiles: Vec<(usize, usize)> = (0..width)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| connections[y][x] != 0)
+        .collect();
+    let (start_x, start_y) = *rng.choose(&loop_tiles);
+    tiles[start_y][start_x] = 'S';
+    let neighbours = [
+        (start_x, start_y - 1, ['|', '7', 'F']),
+        (start_x, start_y + 1, ['|', 'L', 'J']),
+        (start_x - 1, start_y, ['-', 'L', 'F']),
+        (start_x + 1, start_y, ['-', 'J', '7']),
+    ];
+    for (x, y, joining) in neighbours {
+        if connections[y][x] == 0 && joining.contains(&tiles[y][x]) {
+            tiles[y][x] = '.';
+        }
+    }
+
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 10);
+        assert_eq!(input.lines().count(), 32);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+
+    #[test]
+    fn it_draws_a_single_node_as_a_square_loop() {
+        let input = generate(&mut Rng::new(1), 1);
+        assert_eq!(crate::solve_part1(&input).unwrap(), 4);
+        assert_eq!(crate::solve_part2(&input).unwrap(), 1);
+    }
+
+    #[test]
+    fn it_encloses_every_tile_the_tree_covers() {
+        // A tree of n nodes covers them and the two tiles on each of its
+        // n - 1 edges, all of which sit inside the loop, and the loop round
+        // them is 6n + 2 tiles long
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 10);
+            let furthest = crate::solve_part1(&input).unwrap();
+            let inside = crate::solve_part2(&input).unwrap();
+            assert_eq!(furthest, inside + 3);
+        }
+    }
+}
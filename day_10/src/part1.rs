@@ -0,0 +1,348 @@
+use crate::pipe_loop::check_loop;
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Position {
+    x: u16,
+    y: u16,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn get_opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    fn move_position(&self, position: Position) -> Position {
+        match self {
+            Direction::North => Position {
+                x: position.x,
+                y: position.y - 1,
+            },
+            Direction::South => Position {
+                x: position.x,
+                y: position.y + 1,
+            },
+            Direction::East => Position {
+                x: position.x + 1,
+                y: position.y,
+            },
+            Direction::West => Position {
+                x: position.x - 1,
+                y: position.y,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct PipeDirection {
+    from: Direction,
+    to: Direction,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum Segment {
+    Start,
+    Pipe(PipeDirection),
+    Ground,
+}
+
+impl Segment {
+    fn from_char(c: char) -> Option<Segment> {
+        let segment = match c {
+            '|' => Segment::Pipe(PipeDirection {
+                from: Direction::North,
+                to: Direction::South,
+            }),
+            '-' => Segment::Pipe(PipeDirection {
+                from: Direction::East,
+                to: Direction::West,
+            }),
+            'L' => Segment::Pipe(PipeDirection {
+                from: Direction::North,
+                to: Direction::East,
+            }),
+            'J' => Segment::Pipe(PipeDirection {
+                from: Direction::North,
+                to: Direction::West,
+            }),
+            '7' => Segment::Pipe(PipeDirection {
+                from: Direction::South,
+                to: Direction::West,
+            }),
+            'F' => Segment::Pipe(PipeDirection {
+                from: Direction::South,
+                to: Direction::East,
+            }),
+            'S' => Segment::Start,
+            '.' => Segment::Ground,
+            _ => return None,
+        };
+        Some(segment)
+    }
+
+    fn get_reverse(&self) -> Segment {
+        match self {
+            Segment::Pipe(pipe_direction) => Segment::Pipe(PipeDirection {
+                from: pipe_direction.to.clone(),
+                to: pipe_direction.from.clone(),
+            }),
+            _ => self.clone(),
+        }
+    }
+}
+
+fn find_first_segment(
+    start_position: Position,
+    mut segments: Grid<Segment>,
+    reverse: bool,
+) -> (Position, Grid<Segment>) {
+    // Find a suitable segment adjacent to the starting position, skipping
+    // any that would be off the edge of the grid.
+    // If necessary, reverse its direction to fit.
+    let (start_x, start_y) = (start_position.x as usize, start_position.y as usize);
+    let mut neighbours: Vec<(usize, usize)> = segments.neighbours(start_x, start_y).collect();
+
+    if reverse {
+        neighbours.reverse();
+    }
+
+    for (destination_x, destination_y) in neighbours {
+        // The segment has to lead back towards the start
+        let required_direction = if destination_y < start_y {
+            Direction::South
+        } else if destination_y > start_y {
+            Direction::North
+        } else if destination_x < start_x {
+            Direction::East
+        } else {
+            Direction::West
+        };
+        let destination_segment = segments[(destination_x, destination_y)].clone();
+
+        if let Segment::Pipe(ref destination_pipe_direction) = destination_segment {
+            if destination_pipe_direction.from == required_direction {
+                return (
+                    Position {
+                        x: destination_x as u16,
+                        y: destination_y as u16,
+                    },
+                    segments,
+                );
+            } else if destination_pipe_direction.to == required_direction {
+                segments[(destination_x, destination_y)] = destination_segment.get_reverse();
+                return (
+                    Position {
+                        x: destination_x as u16,
+                        y: destination_y as u16,
+                    },
+                    segments,
+                );
+            }
+        }
+    }
+    unreachable!("parse checks that a pipe joins the start");
+}
+
+fn find_next_segment(
+    position: Position,
+    to_direction: Direction,
+    mut segments: Grid<Segment>,
+) -> (Position, Grid<Segment>) {
+    let next_position = to_direction.move_position(position);
+    let next_segment = segments[(next_position.x as usize, next_position.y as usize)].clone();
+    match next_segment {
+        Segment::Pipe(ref next_pipe_direction) => {
+            if next_pipe_direction.from != to_direction.get_opposite() {
+                segments[(next_position.x as usize, next_position.y as usize)] =
+                    next_segment.get_reverse();
+            };
+        }
+        _ => {
+            unreachable!("parse checks that every pipe on the loop leads to another")
+        }
+    };
+
+    (next_position, segments)
+}
+
+fn read_segments(input: &str) -> Result<(Grid<Segment>, Position), ParseError> {
+    let segments = Grid::parse(input, Segment::from_char)?;
+    match segments.position(|segment| *segment == Segment::Start) {
+        Some((x, y)) => Ok((
+            segments,
+            Position {
+                x: x as u16,
+                y: y as u16,
+            },
+        )),
+        None => Err(ParseError::new(0, 0, "", "No start position found")),
+    }
+}
+
+/// The grid of pipe segments and where the animal starts in it.
+pub struct Puzzle {
+    segments: Grid<Segment>,
+    start_position: Position,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let (segments, start_position) = read_segments(input)?;
+    check_loop(input)?;
+    Ok(Puzzle {
+        segments,
+        start_position,
+    })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let Puzzle {
+        mut segments,
+        start_position,
+    } = puzzle;
+
+    let mut current_positions = [start_position.clone(), start_position.clone()];
+
+    let mut steps: u64 = 0;
+    let mut end_reached = false;
+
+    while !end_reached {
+        let current_segments = [
+            segments[(
+                current_positions[0].x as usize,
+                current_positions[0].y as usize,
+            )]
+                .clone(),
+            segments[(
+                current_positions[1].x as usize,
+                current_positions[1].y as usize,
+            )]
+                .clone(),
+        ];
+
+        let mut next_positions: [Option<Position>; 2] = [None, None];
+        for (i, current_segment) in current_segments.iter().enumerate() {
+            let next_position: Position;
+            let updated_segments: Grid<Segment>;
+            match current_segment {
+                Segment::Start => {
+                    (next_position, updated_segments) =
+                        find_first_segment(current_positions[i].clone(), segments, i == 1);
+                }
+
+                Segment::Pipe(pipe_direction) => {
+                    (next_position, updated_segments) = find_next_segment(
+                        current_positions[i].clone(),
+                        pipe_direction.to.clone(),
+                        segments,
+                    );
+                }
+
+                Segment::Ground => {
+                    panic!("We are no longer in a pipe!");
+                }
+            }
+            segments = updated_segments;
+            next_positions[i] = Some(next_position.clone());
+        }
+
+        steps += 1;
+
+        if next_positions[0] == next_positions[1] {
+            end_reached = true;
+        } else {
+            for (i, next_position) in next_positions.iter().enumerate() {
+                match next_position {
+                    Some(next_position) => {
+                        current_positions[i] = next_position.clone();
+                    }
+                    None => {
+                        panic!("No next position found");
+                    }
+                }
+            }
+        };
+    }
+
+    steps
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(steps: u64) -> String {
+    format!("The furthest point is {} steps away", steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.....\n\
+.S-7.\n\
+.|.|.\n\
+.L-J.\n\
+.....";
+
+    const COMPLEX_EXAMPLE: &str = "\
+..F7.\n\
+.FJ|.\n\
+SJ.L7\n\
+|F--J\n\
+LJ...";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 4);
+    }
+
+    #[test]
+    fn it_solves_the_complex_example() {
+        assert_eq!(solve(COMPLEX_EXAMPLE).unwrap(), 8);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 6768);
+    }
+
+    #[test]
+    fn it_starts_from_the_edge_of_the_grid() {
+        assert_eq!(solve("S7\nLJ").unwrap(), 2);
+        assert_eq!(solve("F-7\n|.|\nL-S").unwrap(), 4);
+    }
+
+    #[test]
+    fn it_reports_loops_that_break() {
+        assert_eq!(
+            solve("S."),
+            Err(ParseError::new(0, 0, 'S', "No pipe connects to the start"))
+        );
+        assert_eq!(
+            solve("S-"),
+            Err(ParseError::new(0, 1, '-', "Pipe leads off the grid"))
+        );
+        assert_eq!(
+            solve("S7\n|J"),
+            Err(ParseError::new(1, 0, '|', "Pipe leads off the grid"))
+        );
+    }
+}
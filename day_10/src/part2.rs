@@ -1,5 +1,6 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::pipe_loop::check_loop;
+use aoc_common::ParseError;
+use aoc_grid::Grid;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 struct Position {
@@ -63,8 +64,8 @@ enum Segment {
 }
 
 impl Segment {
-    fn from_char(c: char) -> Segment {
-        match c {
+    fn from_char(c: char) -> Option<Segment> {
+        let segment = match c {
             '|' => Segment::Pipe(PipeDetails {
                 from: Direction::North,
                 to: Direction::South,
@@ -103,110 +104,86 @@ impl Segment {
             }),
             'S' => Segment::Start,
             '.' => Segment::Ground,
-            _ => panic!("Invalid character"),
-        }
+            _ => return None,
+        };
+        Some(segment)
     }
 }
 
 fn find_first_segment(
     start_position: Position,
-    mut segments: Vec<Vec<Segment>>,
+    mut segments: Grid<Segment>,
     reverse: bool,
-) -> (Position, Vec<Vec<Segment>>) {
-    // Find a suitable segment adjacent to the starting position.
+) -> (Position, Grid<Segment>) {
+    // Find a suitable segment adjacent to the starting position, skipping
+    // any that would be off the edge of the grid.
     // If necessary, reverse its direction to fit.
-    // Since S is not along any border, we don't need to
-    // check for out-of-bounds errors :-)
-
-    struct FirstPipeLocations {
-        x_offset: i8,
-        y_offset: i8,
-        required_direction: Direction,
-    }
-
-    let mut first_pipe_locations = [
-        FirstPipeLocations {
-            x_offset: 0,
-            y_offset: -1,
-            required_direction: Direction::South,
-        },
-        FirstPipeLocations {
-            x_offset: 0,
-            y_offset: 1,
-            required_direction: Direction::North,
-        },
-        FirstPipeLocations {
-            x_offset: -1,
-            y_offset: 0,
-            required_direction: Direction::East,
-        },
-        FirstPipeLocations {
-            x_offset: 1,
-            y_offset: 0,
-            required_direction: Direction::West,
-        },
-    ];
+    let (start_x, start_y) = (start_position.x as usize, start_position.y as usize);
+    let mut neighbours: Vec<(usize, usize)> = segments.neighbours(start_x, start_y).collect();
 
     if reverse {
-        first_pipe_locations.reverse();
+        neighbours.reverse();
     }
 
-    for search_location in first_pipe_locations {
-        let destination_x = start_position.x as i16 + search_location.x_offset as i16;
-        let destination_y = start_position.y as i16 + search_location.y_offset as i16;
-        let destination_segment = segments[destination_y as usize][destination_x as usize].clone();
-
-        match destination_segment {
-            Segment::Pipe(ref destination_pipe_details) => {
-                if destination_pipe_details.from == search_location.required_direction {
-                    segments[destination_y as usize][destination_x as usize] =
-                        Segment::Pipe(PipeDetails {
-                            from: destination_pipe_details.from.clone(),
-                            to: destination_pipe_details.to.clone(),
-                            char: destination_pipe_details.char,
-                            main_loop: true,
-                        });
-                    return (
-                        Position {
-                            x: destination_x as u16,
-                            y: destination_y as u16,
-                        },
-                        segments,
-                    );
-                } else if destination_pipe_details.to == search_location.required_direction {
-                    segments[destination_y as usize][destination_x as usize] =
-                        Segment::Pipe(PipeDetails {
-                            from: destination_pipe_details.to.clone(),
-                            to: destination_pipe_details.from.clone(),
-                            char: destination_pipe_details.char,
-                            main_loop: true,
-                        });
-                    return (
-                        Position {
-                            x: destination_x as u16,
-                            y: destination_y as u16,
-                        },
-                        segments,
-                    );
-                }
+    for (destination_x, destination_y) in neighbours {
+        // The segment has to lead back towards the start
+        let required_direction = if destination_y < start_y {
+            Direction::South
+        } else if destination_y > start_y {
+            Direction::North
+        } else if destination_x < start_x {
+            Direction::East
+        } else {
+            Direction::West
+        };
+        let destination_segment = segments[(destination_x, destination_y)].clone();
+
+        if let Segment::Pipe(ref destination_pipe_details) = destination_segment {
+            if destination_pipe_details.from == required_direction {
+                segments[(destination_x, destination_y)] = Segment::Pipe(PipeDetails {
+                    from: destination_pipe_details.from.clone(),
+                    to: destination_pipe_details.to.clone(),
+                    char: destination_pipe_details.char,
+                    main_loop: true,
+                });
+                return (
+                    Position {
+                        x: destination_x as u16,
+                        y: destination_y as u16,
+                    },
+                    segments,
+                );
+            } else if destination_pipe_details.to == required_direction {
+                segments[(destination_x, destination_y)] = Segment::Pipe(PipeDetails {
+                    from: destination_pipe_details.to.clone(),
+                    to: destination_pipe_details.from.clone(),
+                    char: destination_pipe_details.char,
+                    main_loop: true,
+                });
+                return (
+                    Position {
+                        x: destination_x as u16,
+                        y: destination_y as u16,
+                    },
+                    segments,
+                );
             }
-            _ => {}
         }
     }
-    panic!("No first segment found");
+    unreachable!("parse checks that a pipe joins the start");
 }
 
 fn find_next_segment(
     position: Position,
     to_direction: Direction,
-    mut segments: Vec<Vec<Segment>>,
-) -> (Position, Vec<Vec<Segment>>) {
+    mut segments: Grid<Segment>,
+) -> (Position, Grid<Segment>) {
     let next_position = to_direction.move_position(position);
-    let next_segment = segments[next_position.y as usize][next_position.x as usize].clone();
+    let next_segment = segments[(next_position.x as usize, next_position.y as usize)].clone();
     match next_segment {
         Segment::Pipe(ref next_pipe_direction) => {
             if next_pipe_direction.from != to_direction.get_opposite() {
-                segments[next_position.y as usize][next_position.x as usize] =
+                segments[(next_position.x as usize, next_position.y as usize)] =
                     Segment::Pipe(PipeDetails {
                         from: next_pipe_direction.to.clone(),
                         to: next_pipe_direction.from.clone(),
@@ -214,7 +191,7 @@ fn find_next_segment(
                         main_loop: true,
                     });
             } else {
-                segments[next_position.y as usize][next_position.x as usize] =
+                segments[(next_position.x as usize, next_position.y as usize)] =
                     Segment::Pipe(PipeDetails {
                         from: next_pipe_direction.from.clone(),
                         to: next_pipe_direction.to.clone(),
@@ -224,49 +201,34 @@ fn find_next_segment(
             };
         }
         _ => {
-            panic!("Invalid next segment")
+            unreachable!("parse checks that every pipe on the loop leads to another")
         }
     };
 
     (next_position, segments)
 }
 
-fn read_segments() -> (Vec<Vec<Segment>>, Position) {
-    let file = File::open("../input.txt").expect("File not found");
-    let reader = BufReader::new(file);
-
-    let mut lines: Vec<String> = Vec::new();
-    for line in reader.lines() {
-        lines.push(line.unwrap());
-    }
-
-    let mut segments: Vec<Vec<Segment>> = Vec::new();
-    let mut start_position: Option<Position> = None;
-
-    for (y, line) in lines.iter().enumerate() {
-        let mut row_segments: Vec<Segment> = Vec::new();
-        for (x, segment) in line.chars().enumerate() {
-            let segment = Segment::from_char(segment);
-            if segment == Segment::Start {
-                start_position = Some(Position {
-                    x: x as u16,
-                    y: y as u16,
-                });
-            }
-            row_segments.push(segment);
-        }
-        segments.push(row_segments);
+fn read_segments(input: &str) -> Result<(Grid<Segment>, Position), ParseError> {
+    let segments = Grid::parse(input, Segment::from_char)?;
+    match segments.position(|segment| *segment == Segment::Start) {
+        Some((x, y)) => Ok((
+            segments,
+            Position {
+                x: x as u16,
+                y: y as u16,
+            },
+        )),
+        None => Err(ParseError::new(0, 0, "", "No start position found")),
     }
-    (segments, start_position.expect("No start position found"))
 }
 
-fn count_segments_inside_loop(segments: Vec<Vec<Segment>>) -> u64 {
+fn count_segments_inside_loop(segments: Grid<Segment>) -> u64 {
     let mut count: u64 = 0;
-    for row in segments {
+    for row in segments.rows() {
         let mut blocks_to_west = 0;
         let mut section_opened_with: Option<char> = None;
 
-        for segment in &row {
+        for segment in row {
             match segment {
                 Segment::Pipe(pipe_details) => match pipe_details.main_loop {
                     true => match pipe_details.char {
@@ -293,7 +255,6 @@ fn count_segments_inside_loop(segments: Vec<Vec<Segment>>) -> u64 {
                     false => {
                         if blocks_to_west % 2 == 1 {
                             count += 1;
-                        } else {
                         }
                     }
                 },
@@ -319,15 +280,13 @@ fn find_s_equivalent_segment(segment_1: Segment, segment_2: Segment) -> Segment
         _ => panic!("Invalid segment"),
     };
 
-    let s_char: char;
-
-    match (from_direction.clone(), to_direction.clone()) {
-        (Direction::North, Direction::South) | (Direction::South, Direction::North) => s_char = '|',
-        (Direction::East, Direction::West) | (Direction::West, Direction::East) => s_char = '-',
-        (Direction::North, Direction::East) => s_char = 'L',
-        (Direction::North, Direction::West) => s_char = 'J',
-        (Direction::South, Direction::West) => s_char = '7',
-        (Direction::South, Direction::East) => s_char = 'F',
+    let s_char = match (from_direction.clone(), to_direction.clone()) {
+        (Direction::North, Direction::South) | (Direction::South, Direction::North) => '|',
+        (Direction::East, Direction::West) | (Direction::West, Direction::East) => '-',
+        (Direction::North, Direction::East) => 'L',
+        (Direction::North, Direction::West) => 'J',
+        (Direction::South, Direction::West) => '7',
+        (Direction::South, Direction::East) => 'F',
         _ => panic!("Invalid directions"),
     };
 
@@ -339,10 +298,26 @@ fn find_s_equivalent_segment(segment_1: Segment, segment_2: Segment) -> Segment
     })
 }
 
-fn main() {
-    let mut segments: Vec<Vec<Segment>>;
-    let start_position: Position;
-    (segments, start_position) = read_segments();
+/// The grid of pipe segments and where the animal starts in it.
+pub struct Puzzle {
+    segments: Grid<Segment>,
+    start_position: Position,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let (segments, start_position) = read_segments(input)?;
+    check_loop(input)?;
+    Ok(Puzzle {
+        segments,
+        start_position,
+    })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let Puzzle {
+        mut segments,
+        start_position,
+    } = puzzle;
 
     let mut current_positions = [start_position.clone(), start_position.clone()];
     (current_positions[0], segments) =
@@ -350,20 +325,36 @@ fn main() {
     (current_positions[1], segments) =
         find_first_segment(current_positions[1].clone(), segments, true);
     let start_segment_as_pipe = find_s_equivalent_segment(
-        segments[current_positions[0].y as usize][current_positions[0].x as usize].clone(),
-        segments[current_positions[1].y as usize][current_positions[1].x as usize].clone(),
+        segments[(
+            current_positions[0].x as usize,
+            current_positions[0].y as usize,
+        )]
+            .clone(),
+        segments[(
+            current_positions[1].x as usize,
+            current_positions[1].y as usize,
+        )]
+            .clone(),
     );
 
     loop {
         let current_segments = [
-            segments[current_positions[0].y as usize][current_positions[0].x as usize].clone(),
-            segments[current_positions[1].y as usize][current_positions[1].x as usize].clone(),
+            segments[(
+                current_positions[0].x as usize,
+                current_positions[0].y as usize,
+            )]
+                .clone(),
+            segments[(
+                current_positions[1].x as usize,
+                current_positions[1].y as usize,
+            )]
+                .clone(),
         ];
 
         let mut next_positions: [Option<Position>; 2] = [None, None];
         for (i, current_segment) in current_segments.iter().enumerate() {
             let next_position: Position;
-            let updated_segments: Vec<Vec<Segment>>;
+            let updated_segments: Grid<Segment>;
             match current_segment {
                 Segment::Start => {
                     (next_position, updated_segments) =
@@ -404,11 +395,118 @@ fn main() {
 
     // Replace the start segment with a standard pipe segment
     // This is necessary to count the segments inside the loop
-    segments[start_position.y as usize][start_position.x as usize] = start_segment_as_pipe;
+    segments[(start_position.x as usize, start_position.y as usize)] = start_segment_as_pipe;
+
+    count_segments_inside_loop(segments)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).map(solve_puzzle)
+}
 
-    let segments_inside_loop = count_segments_inside_loop(segments);
-    println!(
+pub fn describe(segments_inside_loop: u64) -> String {
+    format!(
         "There are {} segments inside the loop",
         segments_inside_loop
-    );
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........\n\
+.S-------7.\n\
+.|F-----7|.\n\
+.||.....||.\n\
+.||.....||.\n\
+.|L-7.F-J|.\n\
+.|..|.|..|.\n\
+.L--J.L--J.\n\
+...........";
+
+    const SQUEEZED_EXAMPLE: &str = "\
+..........\n\
+.S------7.\n\
+.|F----7|.\n\
+.||....||.\n\
+.||....||.\n\
+.|L-7F-J|.\n\
+.|..||..|.\n\
+.L--JL--J.\n\
+..........";
+
+    const LARGER_EXAMPLE: &str = "\
+.F----7F7F7F7F-7....\n\
+.|F--7||||||||FJ....\n\
+.||.FJ||||||||L7....\n\
+FJL7L7LJLJ||LJ.L-7..\n\
+L--J.L7...LJS7F-7L7.\n\
+....F-J..F7FJ|L7L7L7\n\
+....L7.F7||L7|.L7L7|\n\
+.....|FJLJ|FJ|F7|.LJ\n\
+....FJL-7.||.||||...\n\
+....L---J.LJ.LJLJ...";
+
+    const JUNK_EXAMPLE: &str = "\
+FF7FSF7F7F7F7F7F---7\n\
+L|LJ||||||||||||F--J\n\
+FL-7LJLJ||||||LJL-77\n\
+F--JF--7||LJLJ7F7FJ-\n\
+L---JF-JLJ.||-FJLJJ7\n\
+|F|F-JF---7F7-L7L|7|\n\
+|FFJF7L7F-JF7|JL---7\n\
+7-L-JL7||F7|L7F-7F7|\n\
+L.L7LFJ|||||FJL7||LJ\n\
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 4);
+    }
+
+    #[test]
+    fn it_solves_the_squeezed_example() {
+        assert_eq!(solve(SQUEEZED_EXAMPLE).unwrap(), 4);
+    }
+
+    #[test]
+    fn it_solves_the_larger_example() {
+        assert_eq!(solve(LARGER_EXAMPLE).unwrap(), 8);
+    }
+
+    #[test]
+    fn it_solves_the_example_with_junk_pipes() {
+        assert_eq!(solve(JUNK_EXAMPLE).unwrap(), 10);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 351);
+    }
+
+    #[test]
+    fn it_starts_from_the_edge_of_the_grid() {
+        assert_eq!(solve("S7\nLJ").unwrap(), 0);
+        assert_eq!(solve("F-7\n|.|\nL-S").unwrap(), 1);
+    }
+
+    #[test]
+    fn it_reports_loops_that_break() {
+        assert_eq!(
+            solve("S."),
+            Err(ParseError::new(0, 0, 'S', "No pipe connects to the start"))
+        );
+        assert_eq!(
+            solve("S-"),
+            Err(ParseError::new(0, 1, '-', "Pipe leads off the grid"))
+        );
+        assert_eq!(
+            solve("S7\n|J"),
+            Err(ParseError::new(1, 0, '|', "Pipe leads off the grid"))
+        );
+    }
 }
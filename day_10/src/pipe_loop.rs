@@ -0,0 +1,129 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
+/// The offsets, as `(dx, dy)`, of the two cells a pipe joins.
+fn ends(c: char) -> Option<[(isize, isize); 2]> {
+    match c {
+        '|' => Some([(0, -1), (0, 1)]),
+        '-' => Some([(1, 0), (-1, 0)]),
+        'L' => Some([(0, -1), (1, 0)]),
+        'J' => Some([(0, -1), (-1, 0)]),
+        '7' => Some([(0, 1), (-1, 0)]),
+        'F' => Some([(0, 1), (1, 0)]),
+        _ => None,
+    }
+}
+
+fn step(
+    grid: &Grid<char>,
+    (x, y): (usize, usize),
+    (dx, dy): (isize, isize),
+) -> Option<(usize, usize)> {
+    let x = x.checked_add_signed(dx)?;
+    let y = y.checked_add_signed(dy)?;
+    grid.get(x, y).map(|_| (x, y))
+}
+
+/// Whether the cell at `to` is a pipe with an end facing the cell at `from`.
+fn joins(grid: &Grid<char>, to: (usize, usize), from: (usize, usize)) -> bool {
+    ends(grid[to]).is_some_and(|ends| {
+        ends.iter()
+            .any(|&offset| step(grid, to, offset) == Some(from))
+    })
+}
+
+/// Walks the loop from the start once, so that the solvers can follow it
+/// without checking each step. They set off from the first and the last of
+/// the pipes joining the start, looking north, south, west then east, so
+/// the loop has to leave by one of those and come back by the other.
+pub fn check_loop(input: &str) -> Result<(), ParseError> {
+    let grid = Grid::parse(input, Some)?;
+    let start = grid
+        .position(|&c| c == 'S')
+        .ok_or_else(|| ParseError::new(0, 0, "", "No start position found"))?;
+    let error =
+        |(x, y): (usize, usize), message: &str| ParseError::new(y, x, grid[(x, y)], message);
+
+    let joined: Vec<(usize, usize)> = grid
+        .neighbours(start.0, start.1)
+        .filter(|&neighbour| joins(&grid, neighbour, start))
+        .collect();
+    let (Some(&first), Some(&last)) = (joined.first(), joined.last()) else {
+        return Err(error(start, "No pipe connects to the start"));
+    };
+
+    let mut previous = start;
+    let mut current = first;
+    loop {
+        // Leave by whichever end does not lead back
+        let [end, other_end] = ends(grid[current]).unwrap();
+        let offset = if step(&grid, current, end) == Some(previous) {
+            other_end
+        } else {
+            end
+        };
+        let Some(next) = step(&grid, current, offset) else {
+            return Err(error(current, "Pipe leads off the grid"));
+        };
+
+        if next == start {
+            return if current == last && first != last {
+                Ok(())
+            } else {
+                Err(error(start, "Loop does not close"))
+            };
+        }
+        if grid[next] == '.' {
+            return Err(error(current, "Pipe leads onto ground"));
+        }
+        if !joins(&grid, next, current) {
+            return Err(error(
+                current,
+                "Pipe leads into a pipe that does not join it",
+            ));
+        }
+
+        previous = current;
+        current = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_accepts_a_closed_loop() {
+        assert_eq!(check_loop("S7\nLJ"), Ok(()));
+        assert_eq!(check_loop("F-7\n|.|\nL-S"), Ok(()));
+    }
+
+    #[test]
+    fn it_reports_where_the_loop_breaks() {
+        assert_eq!(
+            check_loop("S."),
+            Err(ParseError::new(0, 0, 'S', "No pipe connects to the start"))
+        );
+        assert_eq!(
+            check_loop("S-"),
+            Err(ParseError::new(0, 1, '-', "Pipe leads off the grid"))
+        );
+        assert_eq!(
+            check_loop("S7\n|J"),
+            Err(ParseError::new(1, 0, '|', "Pipe leads off the grid"))
+        );
+        assert_eq!(
+            check_loop("S-7\n|.|\nL.J"),
+            Err(ParseError::new(2, 0, 'L', "Pipe leads onto ground"))
+        );
+        assert_eq!(
+            check_loop("S-7\n|.|\nL|J"),
+            Err(ParseError::new(
+                2,
+                0,
+                'L',
+                "Pipe leads into a pipe that does not join it"
+            ))
+        );
+    }
+}
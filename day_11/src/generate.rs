@@ -0,0 +1,44 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// The width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 140;
+
+/// An image needs at least one row.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// A `size` by `size` image of galaxies, leaving some rows and columns
+/// empty so that the universe has something to expand.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.07)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(0.07)).collect();
+
+    let mut lines: Vec<String> = Vec::new();
+    for empty_row in &empty_rows {
+        let line: String = empty_columns
+            .iter()
+            .map(|empty_column| {
+                if !empty_row && !empty_column && rng.chance(0.025) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 60);
+        assert_eq!(input.lines().count(), 60);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
@@ -1,46 +1,24 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_common::ParseError;
+use aoc_grid::Grid;
 
 struct Galaxy {
     x: u16,
     y: u16,
 }
 
-fn read_data() -> Vec<Vec<bool>> {
-    let file = File::open("../input.txt").expect("Input file not found");
-    let reader = BufReader::new(file);
-
-    let mut data: Vec<Vec<bool>> = Vec::new();
-
-    for line in reader.lines() {
-        let mut line_data: Vec<bool> = Vec::new();
-        let entries: Vec<char> = line.unwrap().chars().collect();
-
-        for entry in entries {
-            match entry {
-                '.' => line_data.push(false),
-                '#' => line_data.push(true),
-                _ => panic!("Invalid character"),
-            }
-        }
-
-        data.push(line_data);
-    }
-
-    data
+fn read_data(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, |entry| match entry {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
 }
 
-fn find_expanded_rows(data: &Vec<Vec<bool>>) -> Vec<u16> {
+fn find_expanded_rows(data: &Grid<bool>) -> Vec<u16> {
     let mut expanded_rows: Vec<u16> = Vec::new();
 
-    for y in 0..data.len() {
-        let mut galaxy_in_row = false;
-        for x in 0..data[0].len() {
-            if data[y][x] {
-                galaxy_in_row = true;
-                break;
-            }
-        }
+    for (y, row) in data.rows().enumerate() {
+        let galaxy_in_row = row.iter().any(|&galaxy| galaxy);
         if !galaxy_in_row {
             expanded_rows.push(y.try_into().unwrap());
         }
@@ -49,17 +27,11 @@ fn find_expanded_rows(data: &Vec<Vec<bool>>) -> Vec<u16> {
     expanded_rows
 }
 
-fn find_expanded_columns(data: &Vec<Vec<bool>>) -> Vec<u16> {
+fn find_expanded_columns(data: &Grid<bool>) -> Vec<u16> {
     let mut expanded_columns: Vec<u16> = Vec::new();
 
-    for x in 0..data[0].len() {
-        let mut galaxy_in_column = false;
-        for y in 0..data.len() {
-            if data[y][x] {
-                galaxy_in_column = true;
-                break;
-            }
-        }
+    for (x, mut column) in data.columns().enumerate() {
+        let galaxy_in_column = column.any(|&galaxy| galaxy);
         if !galaxy_in_column {
             expanded_columns.push(x.try_into().unwrap());
         }
@@ -68,25 +40,33 @@ fn find_expanded_columns(data: &Vec<Vec<bool>>) -> Vec<u16> {
     expanded_columns
 }
 
-fn find_galaxies(data: &Vec<Vec<bool>>) -> Vec<Galaxy> {
+fn find_galaxies(data: &Grid<bool>) -> Vec<Galaxy> {
     let mut galaxies: Vec<Galaxy> = Vec::new();
 
-    for y in 0..data.len() {
-        for x in 0..data[0].len() {
-            if data[y][x] {
-                galaxies.push(Galaxy {
-                    x: x.try_into().unwrap(),
-                    y: y.try_into().unwrap(),
-                });
-            }
+    for ((x, y), &galaxy) in data.iter() {
+        if galaxy {
+            galaxies.push(Galaxy {
+                x: x.try_into().unwrap(),
+                y: y.try_into().unwrap(),
+            });
         }
     }
 
     galaxies
 }
 
-fn main() {
-    let data = read_data();
+/// The image of the universe from the puzzle input.
+pub struct Puzzle {
+    image: Grid<bool>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let image = read_data(input)?;
+    Ok(Puzzle { image })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let data = puzzle.image;
     let expanded_rows = find_expanded_rows(&data);
     let expanded_columns = find_expanded_columns(&data);
     let galaxies = find_galaxies(&data);
@@ -125,6 +105,42 @@ fn main() {
         }
     }
 
-    let sum_of_distances: u64 = distances.iter().sum();
-    println!("Sum of distances: {}", sum_of_distances);
+    distances.iter().sum()
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(sum_of_distances: u64) -> String {
+    format!("Sum of distances: {}", sum_of_distances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......\n\
+.......#..\n\
+#.........\n\
+..........\n\
+......#...\n\
+.#........\n\
+.........#\n\
+..........\n\
+.......#..\n\
+#...#.....";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 374);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 9545480);
+    }
 }
@@ -0,0 +1,54 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// The number of rows in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// An input needs at least one row of springs.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// `size` rows of springs, each made by laying out a random row of
+/// operational and damaged springs, listing its groups of damaged springs,
+/// and then hiding the condition of some of the springs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    while lines.len() < size {
+        let length = rng.between(1, 20) as usize;
+        let damaged: Vec<bool> = (0..length).map(|_| rng.chance(0.45)).collect();
+
+        let groups: Vec<String> = damaged
+            .split(|&is_damaged| !is_damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        if groups.is_empty() {
+            continue;
+        }
+
+        let layout: String = damaged
+            .iter()
+            .map(|&is_damaged| match (rng.chance(0.5), is_damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        lines.push(format!("{} {}", layout, groups.join(",")));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_rows_with_at_least_one_arrangement() {
+        let input = generate(&mut Rng::new(7), 100);
+        assert_eq!(input.lines().count(), 100);
+        for line in input.lines() {
+            assert!(crate::solve_part1(line).unwrap() >= 1, "{}", line);
+        }
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub use part1::solve as solve_part1;
+pub use part2::solve as solve_part2;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
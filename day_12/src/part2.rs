@@ -1,6 +1,6 @@
+use aoc_common::error::words;
+use aoc_common::ParseError;
 use memoize::memoize;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
 enum Condition {
@@ -10,12 +10,12 @@ enum Condition {
 }
 
 impl Condition {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '.' => Condition::Operational,
-            '#' => Condition::Damaged,
-            '?' => Condition::Unknown,
-            _ => panic!("Invalid condition char: {}", c),
+            '.' => Some(Condition::Operational),
+            '#' => Some(Condition::Damaged),
+            '?' => Some(Condition::Unknown),
+            _ => None,
         }
     }
 }
@@ -26,27 +26,36 @@ struct RowData {
     damaged_groups: Vec<u64>,
 }
 
-fn parse_row(row: &str) -> RowData {
-    let row_sections = row.split_whitespace();
-    let layout: Vec<Condition> = row_sections
-        .clone()
-        .nth(0)
-        .unwrap()
-        .chars()
-        .map(|c| Condition::from_char(c))
-        .collect();
-    let damaged_groups: Vec<u64> = row_sections
-        .clone()
-        .nth(1)
-        .unwrap()
-        .split(",")
-        .map(|s| s.parse().unwrap())
-        .collect();
+fn parse_row(row: &str, line: usize) -> Result<RowData, ParseError> {
+    let mut row_sections = words(row);
+    let (layout_column, layout_section) = row_sections
+        .next()
+        .ok_or_else(|| ParseError::new(line, 0, "", "Missing spring layout"))?;
+    let (groups_column, groups_section) = row_sections
+        .next()
+        .ok_or_else(|| ParseError::new(line, row.len(), "", "Missing damaged groups"))?;
 
-    RowData {
+    let mut layout: Vec<Condition> = Vec::new();
+    for (i, c) in layout_section.chars().enumerate() {
+        let condition = Condition::from_char(c)
+            .ok_or_else(|| ParseError::new(line, layout_column + i, c, "Invalid condition"))?;
+        layout.push(condition);
+    }
+
+    let mut damaged_groups: Vec<u64> = Vec::new();
+    let mut group_column = groups_column;
+    for group in groups_section.split(',') {
+        let group_size = group
+            .parse()
+            .map_err(|_| ParseError::new(line, group_column, group, "Invalid group size"))?;
+        damaged_groups.push(group_size);
+        group_column += group.chars().count() + 1;
+    }
+
+    Ok(RowData {
         layout,
         damaged_groups,
-    }
+    })
 }
 
 fn unfold_row(row_data: &RowData) -> RowData {
@@ -74,7 +83,7 @@ fn get_row_permutations(row_data: RowData, mut damaged_streak: u64) -> u64 {
     for (i, condition) in row_data.layout.iter().enumerate() {
         match condition {
             Condition::Damaged => {
-                if damaged_groups.len() == 0 || must_be_operational {
+                if damaged_groups.is_empty() || must_be_operational {
                     return 0;
                 }
                 damaged_streak += 1;
@@ -119,34 +128,76 @@ fn get_row_permutations(row_data: RowData, mut damaged_streak: u64) -> u64 {
         }
     }
 
-    if damaged_groups.len() == 0 {
-        return 1;
+    if damaged_groups.is_empty() {
+        1
     } else {
-        return 0;
+        0
     }
 }
 
-fn main() {
-    let file = File::open("../input.txt").unwrap();
-    let reader = BufReader::new(file);
+/// The rows of the condition records in the puzzle input.
+pub struct Puzzle {
+    rows: Vec<RowData>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| parse_row(line, line_number))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Puzzle { rows })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    // The memo table outlives each call, so clear it to keep repeated solves
+    // of the same input (e.g. when benchmarking) from becoming lookups
+    memoized_flush_get_row_permutations();
 
     let mut sum_of_arrangements: u64 = 0;
-    for line in reader.lines() {
-        let mut row = parse_row(&line.unwrap());
-        row = unfold_row(&row);
-        sum_of_arrangements += get_row_permutations(row, 0) as u64;
+    for row in &puzzle.rows {
+        sum_of_arrangements += get_row_permutations(unfold_row(row), 0);
     }
 
-    println!("Sum of arrangements: {}", sum_of_arrangements);
+    sum_of_arrangements
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(sum_of_arrangements: u64) -> String {
+    format!("Sum of arrangements: {}", sum_of_arrangements)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = "\
+???.### 1,1,3\n\
+.??..??...?##. 1,1,3\n\
+?#?#?#?#?#?#?#? 1,3,1,6\n\
+????.#...#... 4,1,1\n\
+????.######..#####. 1,6,5\n\
+?###???????? 3,2,1";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 525152);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 7030194981795);
+    }
+
     #[test]
     fn it_parses_row_1() {
-        let row = parse_row("???.### 1,1,3");
+        let row = parse_row("???.### 1,1,3", 0).unwrap();
         assert_eq!(
             row,
             RowData {
@@ -164,45 +215,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_reports_invalid_conditions() {
+        let error = parse_row("??x.### 1,1,3", 4).unwrap_err();
+        assert_eq!(error, ParseError::new(4, 2, "x", "Invalid condition"));
+    }
+
+    #[test]
+    fn it_reports_invalid_group_sizes() {
+        let error = parse_row("???.### 1,a,3", 0).unwrap_err();
+        assert_eq!(error, ParseError::new(0, 10, "a", "Invalid group size"));
+    }
+
     #[test]
     fn it_evaluates_example_row_1() {
-        let row = parse_row("???.### 1,1,3");
+        let row = parse_row("???.### 1,1,3", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 1);
     }
 
     #[test]
     fn it_evaluates_example_row_2() {
-        let row = parse_row(".??..??...?##. 1,1,3");
+        let row = parse_row(".??..??...?##. 1,1,3", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 4);
     }
 
     #[test]
     fn it_evaluates_example_row_3() {
-        let row = parse_row("?#?#?#?#?#?#?#? 1,3,1,6");
+        let row = parse_row("?#?#?#?#?#?#?#? 1,3,1,6", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 1);
     }
 
     #[test]
     fn it_evaluates_example_row_4() {
-        let row = parse_row("????.#...#... 4,1,1");
+        let row = parse_row("????.#...#... 4,1,1", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 1);
     }
 
     #[test]
     fn it_evaluates_example_row_5() {
-        let row = parse_row("????.######..#####. 1,6,5");
+        let row = parse_row("????.######..#####. 1,6,5", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 4);
     }
 
     #[test]
     fn it_evaluates_example_row_6() {
-        let row = parse_row("?###???????? 3,2,1");
+        let row = parse_row("?###???????? 3,2,1", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 10);
     }
 
     #[test]
     fn it_evaluates_edge_case() {
-        let row = parse_row("..?????#?? 4,1");
+        let row = parse_row("..?????#?? 4,1", 0).unwrap();
         assert_eq!(get_row_permutations(row, 0), 2);
     }
 }
@@ -0,0 +1,153 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// The number of patterns in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// An input needs at least one pattern.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// A line of reflection, lying just after the given row or column.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Line {
+    AfterRow(usize),
+    AfterColumn(usize),
+}
+
+impl Line {
+    /// Every pair of tiles that the line reflects onto each other.
+    fn pairs(&self, width: usize, height: usize) -> Vec<((usize, usize), (usize, usize))> {
+        let mut pairs = Vec::new();
+        match *self {
+            Line::AfterRow(row) => {
+                for offset in 0..(row + 1).min(height - row - 1) {
+                    for x in 0..width {
+                        pairs.push(((x, row - offset), (x, row + 1 + offset)));
+                    }
+                }
+            }
+            Line::AfterColumn(column) => {
+                for offset in 0..(column + 1).min(width - column - 1) {
+                    for y in 0..height {
+                        pairs.push(((column - offset, y), (column + 1 + offset, y)));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+fn find(parents: &mut [usize], tile: usize) -> usize {
+    if parents[tile] != tile {
+        parents[tile] = find(parents, parents[tile]);
+    }
+    parents[tile]
+}
+
+/// A pattern with exactly one clean line of reflection for part 1 and
+/// exactly one other line that is one smudge away from being a reflection
+/// for part 2.
+fn generate_pattern(rng: &mut Rng) -> Vec<Vec<bool>> {
+    loop {
+        let width = rng.between(5, 17) as usize;
+        let height = rng.between(5, 17) as usize;
+        let mut lines: Vec<Line> = (0..height - 1)
+            .map(Line::AfterRow)
+            .chain((0..width - 1).map(Line::AfterColumn))
+            .collect();
+        rng.shuffle(&mut lines);
+        let (clean, smudged) = (lines[0], lines[1]);
+
+        // Tiles that either line reflects onto each other must match, so
+        // group them together and pick one value per group
+        let mut parents: Vec<usize> = (0..width * height).collect();
+        for line in [clean, smudged] {
+            for ((x1, y1), (x2, y2)) in line.pairs(width, height) {
+                let a = find(&mut parents, y1 * width + x1);
+                let b = find(&mut parents, y2 * width + x2);
+                parents[a] = b;
+            }
+        }
+        let values: Vec<bool> = (0..width * height).map(|_| rng.chance(0.5)).collect();
+        let mut pattern: Vec<Vec<bool>> = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| values[find(&mut parents, y * width + x)])
+                    .collect()
+            })
+            .collect();
+
+        // Smudge a tile that only the second line reflects
+        let clean_tiles: Vec<(usize, usize)> = clean
+            .pairs(width, height)
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect();
+        let smudgeable: Vec<(usize, usize)> = smudged
+            .pairs(width, height)
+            .into_iter()
+            .map(|(a, _)| a)
+            .filter(|tile| !clean_tiles.contains(tile))
+            .collect();
+        if smudgeable.is_empty() {
+            continue;
+        }
+        let (x, y) = *rng.choose(&smudgeable);
+        pattern[y][x] = !pattern[y][x];
+
+        // The random values may have made other lines reflect too
+        let differences: Vec<(Line, usize)> = lines
+            .iter()
+            .map(|line| {
+                let count = line
+                    .pairs(width, height)
+                    .iter()
+                    .filter(|((x1, y1), (x2, y2))| pattern[*y1][*x1] != pattern[*y2][*x2])
+                    .count();
+                (*line, count)
+            })
+            .collect();
+        let with = |count: usize| -> Vec<Line> {
+            differences
+                .iter()
+                .filter(|(_, differences)| *differences == count)
+                .map(|(line, _)| *line)
+                .collect()
+        };
+        if with(0) == [clean] && with(1) == [smudged] {
+            return pattern;
+        }
+    }
+}
+
+/// `size` patterns of ash and rocks separated by blank lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut patterns: Vec<String> = Vec::new();
+    for _ in 0..size {
+        let pattern = generate_pattern(rng);
+        let lines: Vec<String> = pattern
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&rock| if rock { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        patterns.push(lines.join("\n"));
+    }
+    patterns.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 50);
+        assert_eq!(input.split("\n\n").count(), 50);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
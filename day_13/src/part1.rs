@@ -0,0 +1,176 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
+fn calculate_row_hashes(rock_locations: &Grid<bool>) -> Vec<u32> {
+    let mut hashes: Vec<u32> = Vec::new();
+    for row in rock_locations.rows() {
+        let mut hash: u32 = 0;
+        for rock in row {
+            hash <<= 1;
+            if *rock {
+                hash += 1;
+            }
+        }
+        hashes.push(hash);
+    }
+    hashes
+}
+
+fn calculate_column_hashes(rock_locations: &Grid<bool>) -> Vec<u32> {
+    calculate_row_hashes(&rock_locations.transpose())
+}
+
+fn calculate_pattern_value(pattern: &Grid<bool>) -> Option<u16> {
+    let row_hashes = calculate_row_hashes(pattern);
+    let mut mirror_row: Option<u32> = None;
+    for i in 0..row_hashes.len() - 1 {
+        if row_hashes[i] == row_hashes[i + 1] {
+            mirror_row = Some(i as u32);
+            let mut offset = 1;
+            while i + 1 + offset < row_hashes.len() && i >= offset {
+                if row_hashes[i - offset] == row_hashes[i + 1 + offset] {
+                    offset += 1;
+                } else {
+                    mirror_row = None;
+                    break;
+                }
+            }
+            if mirror_row.is_some() {
+                break;
+            }
+        }
+    }
+
+    match mirror_row {
+        Some(row) => Some(((row + 1) * 100).try_into().unwrap()),
+        None => {
+            let column_hashes = calculate_column_hashes(pattern);
+            let mut mirror_column: Option<u32> = None;
+            for i in 0..column_hashes.len() - 1 {
+                if column_hashes[i] == column_hashes[i + 1] {
+                    mirror_column = Some(i as u32);
+                    let mut offset = 1;
+                    while i + 1 + offset < column_hashes.len() && i >= offset {
+                        if column_hashes[i - offset] == column_hashes[i + 1 + offset] {
+                            offset += 1;
+                        } else {
+                            mirror_column = None;
+                            break;
+                        }
+                    }
+                    if mirror_column.is_some() {
+                        break;
+                    }
+                }
+            }
+
+            mirror_column.map(|column| (column + 1).try_into().unwrap())
+        }
+    }
+}
+
+/// Reads every pattern along with the line it starts on.
+fn read_patterns(input: &str) -> Result<Vec<(usize, Grid<bool>)>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut patterns: Vec<(usize, Grid<bool>)> = Vec::new();
+    let mut first_line = 0;
+
+    for pattern_lines in lines.split(|line| line.is_empty()) {
+        if !pattern_lines.is_empty() {
+            let pattern = Grid::parse(&pattern_lines.join("\n"), |c| match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            })
+            .map_err(|mut error| {
+                error.line += first_line;
+                error
+            })?;
+            patterns.push((first_line, pattern));
+        }
+        first_line += pattern_lines.len() + 1;
+    }
+
+    Ok(patterns)
+}
+
+/// The patterns of ash and rocks in the puzzle input, each with the line it
+/// starts on.
+pub struct Puzzle {
+    patterns: Vec<(usize, Grid<bool>)>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let patterns = read_patterns(input)?;
+    Ok(Puzzle { patterns })
+}
+
+/// Adds up the value of every pattern, reporting a pattern with no
+/// reflection against its first line.
+pub fn solve_puzzle(puzzle: Puzzle) -> Result<u64, ParseError> {
+    let mut total_value: u64 = 0;
+
+    for (first_line, rock_locations) in puzzle.patterns {
+        let value = calculate_pattern_value(&rock_locations).ok_or_else(|| {
+            let first_row: String = rock_locations
+                .rows()
+                .next()
+                .unwrap()
+                .iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect();
+            ParseError::new(first_line, 0, first_row, "No reflection found")
+        })?;
+        total_value += value as u64;
+    }
+
+    Ok(total_value)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).and_then(solve_puzzle)
+}
+
+pub fn describe(total_value: u64) -> String {
+    format!("Total value: {}", total_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.\n\
+..#.##.#.\n\
+##......#\n\
+##......#\n\
+..#.##.#.\n\
+..##..##.\n\
+#.#.##.#.\n\
+\n\
+#...##..#\n\
+#....#..#\n\
+..##..###\n\
+#####.##.\n\
+#####.##.\n\
+..##..###\n\
+#....#..#";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 405);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 33780);
+    }
+
+    #[test]
+    fn it_reports_patterns_without_a_reflection() {
+        let error = solve("##\n..\n\n#.\n.#").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 0, "#.", "No reflection found"));
+    }
+}
@@ -1,8 +1,8 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_common::ParseError;
+use aoc_grid::Grid;
 
 fn check_for_power_of_two(x: u32) -> bool {
-    return x != 0 && (x & (x - 1)) == 0;
+    x != 0 && (x & (x - 1)) == 0
 }
 
 fn check_for_smudged_reflection(hash_1: u32, hash_2: u32) -> bool {
@@ -13,9 +13,9 @@ fn check_for_smudged_reflection(hash_1: u32, hash_2: u32) -> bool {
     false
 }
 
-fn calculate_row_hashes(rock_locations: &Vec<Vec<bool>>) -> Vec<u32> {
+fn calculate_row_hashes(rock_locations: &Grid<bool>) -> Vec<u32> {
     let mut hashes: Vec<u32> = Vec::new();
-    for row in rock_locations {
+    for row in rock_locations.rows() {
         let mut hash: u32 = 0;
         for rock in row {
             hash <<= 1;
@@ -28,22 +28,11 @@ fn calculate_row_hashes(rock_locations: &Vec<Vec<bool>>) -> Vec<u32> {
     hashes
 }
 
-fn calculate_column_hashes(rock_locations: &Vec<Vec<bool>>) -> Vec<u32> {
-    let mut hashes: Vec<u32> = Vec::new();
-    for i in 0..rock_locations[0].len() {
-        let mut hash: u32 = 0;
-        for j in 0..rock_locations.len() {
-            hash <<= 1;
-            if rock_locations[j][i] {
-                hash += 1;
-            }
-        }
-        hashes.push(hash);
-    }
-    hashes
+fn calculate_column_hashes(rock_locations: &Grid<bool>) -> Vec<u32> {
+    calculate_row_hashes(&rock_locations.transpose())
 }
 
-fn calculate_pattern_value(pattern: &Vec<Vec<bool>>) -> u16 {
+fn calculate_pattern_value(pattern: &Grid<bool>) -> Option<u16> {
     // This function really needs some refactoring but writing production-grade code
     // is not my goal for advent of code. If you are reading this - sorry
     let row_hashes = calculate_row_hashes(pattern);
@@ -54,7 +43,7 @@ fn calculate_pattern_value(pattern: &Vec<Vec<bool>>) -> u16 {
         let mut is_true_reflection = row_hashes[i] == row_hashes[i + 1];
         let mut is_smudged_reflection =
             check_for_smudged_reflection(row_hashes[i], row_hashes[i + 1]);
-        smudge_found = is_smudged_reflection.clone();
+        smudge_found = is_smudged_reflection;
         if is_true_reflection || is_smudged_reflection {
             mirror_row = Some(i as u32);
             let mut offset = 1;
@@ -88,9 +77,7 @@ fn calculate_pattern_value(pattern: &Vec<Vec<bool>>) -> u16 {
     }
 
     match mirror_row {
-        Some(row) => {
-            return ((row + 1) * 100).try_into().unwrap();
-        }
+        Some(row) => Some(((row + 1) * 100).try_into().unwrap()),
         None => {
             let column_hashes = calculate_column_hashes(pattern);
             let mut mirror_column: Option<u32> = None;
@@ -100,7 +87,7 @@ fn calculate_pattern_value(pattern: &Vec<Vec<bool>>) -> u16 {
                 let mut is_true_reflection = column_hashes[i] == column_hashes[i + 1];
                 let mut is_smudged_reflection =
                     check_for_smudged_reflection(column_hashes[i], column_hashes[i + 1]);
-                smudge_found = is_smudged_reflection.clone();
+                smudge_found = is_smudged_reflection;
                 if is_true_reflection || is_smudged_reflection {
                     mirror_column = Some(i as u32);
                     let mut offset = 1;
@@ -134,36 +121,113 @@ fn calculate_pattern_value(pattern: &Vec<Vec<bool>>) -> u16 {
                 }
             }
 
-            match mirror_column {
-                Some(column) => {
-                    return (column + 1).try_into().unwrap();
-                }
-                None => {
-                    panic!("No mirror found");
-                }
-            }
+            mirror_column.map(|column| (column + 1).try_into().unwrap())
         }
     }
 }
 
-fn main() {
-    let file = File::open("../input.txt").unwrap();
-    let reader = BufReader::new(file);
+/// Reads every pattern along with the line it starts on.
+fn read_patterns(input: &str) -> Result<Vec<(usize, Grid<bool>)>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut patterns: Vec<(usize, Grid<bool>)> = Vec::new();
+    let mut first_line = 0;
 
-    let mut total_value: u64 = 0;
-    let mut rock_locations: Vec<Vec<bool>> = Vec::new();
-
-    for line in reader.lines() {
-        let row = line.unwrap();
-        if row.len() == 0 {
-            total_value += calculate_pattern_value(&rock_locations) as u64;
-            rock_locations = Vec::new();
-        } else {
-            let rock_row: Vec<bool> = row.chars().map(|c| c == '#').collect();
-            rock_locations.push(rock_row);
+    for pattern_lines in lines.split(|line| line.is_empty()) {
+        if !pattern_lines.is_empty() {
+            let pattern = Grid::parse(&pattern_lines.join("\n"), |c| match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            })
+            .map_err(|mut error| {
+                error.line += first_line;
+                error
+            })?;
+            patterns.push((first_line, pattern));
         }
+        first_line += pattern_lines.len() + 1;
     }
-    total_value += calculate_pattern_value(&rock_locations) as u64;
 
-    println!("Total value: {}", total_value);
+    Ok(patterns)
+}
+
+/// The patterns of ash and rocks in the puzzle input, each with the line it
+/// starts on.
+pub struct Puzzle {
+    patterns: Vec<(usize, Grid<bool>)>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let patterns = read_patterns(input)?;
+    Ok(Puzzle { patterns })
+}
+
+/// Adds up the value of every pattern, reporting a pattern with no
+/// reflection against its first line.
+pub fn solve_puzzle(puzzle: Puzzle) -> Result<u64, ParseError> {
+    let mut total_value: u64 = 0;
+
+    for (first_line, rock_locations) in puzzle.patterns {
+        let value = calculate_pattern_value(&rock_locations).ok_or_else(|| {
+            let first_row: String = rock_locations
+                .rows()
+                .next()
+                .unwrap()
+                .iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect();
+            ParseError::new(first_line, 0, first_row, "No reflection found")
+        })?;
+        total_value += value as u64;
+    }
+
+    Ok(total_value)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).and_then(solve_puzzle)
+}
+
+pub fn describe(total_value: u64) -> String {
+    format!("Total value: {}", total_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.\n\
+..#.##.#.\n\
+##......#\n\
+##......#\n\
+..#.##.#.\n\
+..##..##.\n\
+#.#.##.#.\n\
+\n\
+#...##..#\n\
+#....#..#\n\
+..##..###\n\
+#####.##.\n\
+#####.##.\n\
+..##..###\n\
+#....#..#";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 400);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 23479);
+    }
+
+    #[test]
+    fn it_reports_patterns_without_a_reflection() {
+        let error = solve("#.\n..\n\n#.\n.#").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 0, "#.", "No reflection found"));
+    }
 }
@@ -0,0 +1,37 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// The width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A platform needs at least one row.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// A `size` by `size` platform scattered with round and cube-shaped rocks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for _ in 0..size {
+        let line: String = (0..size)
+            .map(|_| match rng.below(10) {
+                0..=1 => 'O',
+                2 => '#',
+                _ => '.',
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 30);
+        assert_eq!(input.lines().count(), 30);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
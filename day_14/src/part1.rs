@@ -0,0 +1,114 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+use std::fmt;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Rock {
+    Round,
+    Cubic,
+    None,
+}
+
+impl Rock {
+    fn from_char(c: char) -> Option<Rock> {
+        match c {
+            '.' => Some(Rock::None),
+            '#' => Some(Rock::Cubic),
+            'O' => Some(Rock::Round),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Rock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rock::Round => write!(f, "O"),
+            Rock::Cubic => write!(f, "#"),
+            Rock::None => write!(f, "."),
+        }
+    }
+}
+
+fn tilt_north(platform: &mut Grid<Rock>) {
+    let mut something_moved = true;
+    while something_moved {
+        something_moved = false;
+        for row in 1..platform.height() {
+            for column in 0..platform.width() {
+                if platform[(column, row)] == Rock::Round
+                    && platform[(column, row - 1)] == Rock::None
+                {
+                    platform[(column, row)] = Rock::None;
+                    platform[(column, row - 1)] = Rock::Round;
+                    something_moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn calculate_load_on_north(platform: &Grid<Rock>) -> u64 {
+    let mut load: u64 = 0;
+    for (row, rocks) in platform.rows().enumerate() {
+        let row_multiplier = platform.height() - row;
+        let round_rock_count = rocks.iter().filter(|&x| *x == Rock::Round).count();
+        load += (round_rock_count as u64) * (row_multiplier as u64);
+    }
+    load
+}
+
+/// The platform of rocks from the puzzle input.
+pub struct Puzzle {
+    platform: Grid<Rock>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let platform = Grid::parse(input, Rock::from_char)?;
+    Ok(Puzzle { platform })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let mut platform = puzzle.platform;
+
+    tilt_north(&mut platform);
+
+    calculate_load_on_north(&platform)
+}
+
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(load: u64) -> String {
+    format!("Load on north: {}", load)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....\n\
+O.OO#....#\n\
+.....##...\n\
+OO.#O....O\n\
+.O.....O#.\n\
+O.#..O.#.#\n\
+..O..#O..O\n\
+.......O..\n\
+#....###..\n\
+#OO..#....";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 136);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 105623);
+    }
+}
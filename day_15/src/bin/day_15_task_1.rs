@@ -0,0 +1,15 @@
+use aoc_common::Input;
+use day_15::part1::describe;
+
+fn main() {
+    let input = Input::from_args_or(day_15::INPUT_PATH)
+        .read()
+        .expect("Failed to read input");
+    match day_15::solve_part1(&input) {
+        Ok(answer) => println!("{}", describe(answer)),
+        Err(error) => {
+            eprintln!("{}", error.render(&input));
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// Roughly the number of steps in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 4000;
+
+/// An initialization sequence needs at least one step.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// An initialization sequence of `size` steps. The labels are drawn from a
+/// pool much smaller than the sequence, so lenses are often replaced and
+/// removed after being inserted.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..size / 10 + 1)
+        .map(|_| {
+            let length = rng.between(2, 6);
+            (0..length)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.between(1, 9))
+            }
+        })
+        .collect();
+    steps.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 500);
+        assert_eq!(input.split(',').count(), 500);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
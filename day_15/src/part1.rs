@@ -0,0 +1,81 @@
+use aoc_common::ParseError;
+
+fn evaluate_string_hash(input: &str) -> u32 {
+    let mut current_value: u32 = 0;
+    for c in input.chars() {
+        current_value += c as u32;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value
+}
+
+/// The comma separated steps of the initialization sequence.
+pub struct Puzzle {
+    steps: Vec<String>,
+}
+
+pub fn parse(input_string: &str) -> Result<Puzzle, ParseError> {
+    let steps = input_string
+        .trim_end()
+        .split(',')
+        .map(|step| step.to_string())
+        .collect();
+    Ok(Puzzle { steps })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let mut total_hash: u64 = 0;
+    for input in puzzle.steps {
+        total_hash += evaluate_string_hash(&input) as u64;
+    }
+    total_hash
+}
+
+pub fn solve(input_string: &str) -> Result<u64, ParseError> {
+    parse(input_string).map(solve_puzzle)
+}
+
+pub fn describe(total_hash: u64) -> String {
+    format!("Sum of hashes: {}", total_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 1320);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 501680);
+    }
+
+    #[test]
+    fn it_evaluates_example_1() {
+        assert_eq!(evaluate_string_hash("rn=1"), 30);
+    }
+
+    #[test]
+    fn it_evaluates_example_2() {
+        assert_eq!(evaluate_string_hash("cm-"), 253);
+    }
+
+    #[test]
+    fn it_evaluates_example_3() {
+        assert_eq!(evaluate_string_hash("qp=3"), 97);
+    }
+
+    #[test]
+    fn it_evaluates_example_4() {
+        assert_eq!(evaluate_string_hash("ot=7"), 231);
+    }
+}
@@ -0,0 +1,211 @@
+use aoc_common::ParseError;
+use std::collections::HashMap;
+
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+fn evaluate_string_hash(input: &str) -> u8 {
+    let mut current_value: u32 = 0;
+    for c in input.chars() {
+        current_value += c as u32;
+        current_value *= 17;
+        current_value %= 256;
+    }
+    current_value as u8
+}
+
+fn add_lens(box_number: u8, label: String, focal_length: u8, boxes: &mut HashMap<u8, Vec<Lens>>) {
+    match boxes.get_mut(&box_number) {
+        Some(lenses) => {
+            let mut label_found = false;
+            for lens in lenses.iter_mut() {
+                if lens.label == label {
+                    lens.focal_length = focal_length;
+                    label_found = true;
+                    break;
+                }
+            }
+
+            if !label_found {
+                lenses.push(Lens {
+                    label,
+                    focal_length,
+                });
+            }
+        }
+
+        None => {
+            boxes.insert(
+                box_number,
+                vec![Lens {
+                    label,
+                    focal_length,
+                }],
+            );
+        }
+    }
+}
+
+fn remove_lens(box_number: u8, label: String, boxes: &mut HashMap<u8, Vec<Lens>>) {
+    if let Some(lenses) = boxes.get_mut(&box_number) {
+        for i in 0..lenses.len() {
+            if lenses[i].label == label {
+                lenses.remove(i);
+                break;
+            }
+        }
+    };
+}
+
+fn calculate_focusing_power(boxes: &HashMap<u8, Vec<Lens>>) -> u64 {
+    let mut focusing_power: u64 = 0;
+    for (box_number, lenses) in boxes {
+        for (slot_number, lens) in lenses.iter().enumerate() {
+            focusing_power +=
+                (*box_number as u64 + 1) * (slot_number as u64 + 1) * lens.focal_length as u64;
+        }
+    }
+    focusing_power
+}
+
+enum Operation {
+    Remove { label: String },
+    Insert { label: String, focal_length: u8 },
+}
+
+/// The steps of the initialization sequence.
+pub struct Puzzle {
+    operations: Vec<Operation>,
+}
+
+pub fn parse(input_string: &str) -> Result<Puzzle, ParseError> {
+    let inputs: Vec<&str> = input_string.trim_end().split(',').collect();
+
+    let mut operations: Vec<Operation> = Vec::new();
+    let mut input_offset = 0;
+
+    for input in inputs {
+        let operation_position = input.find(['=', '-']).ok_or_else(|| {
+            ParseError::at_offset(input_string, input_offset, input, "No operation found")
+        })?;
+        let label = input[0..operation_position].to_string();
+        let operation = input[operation_position..operation_position + 1].to_string();
+
+        match operation.as_str() {
+            "=" => {
+                let focal_length_text = &input[operation_position + 1..];
+                let focal_length = match focal_length_text.parse::<u8>() {
+                    Ok(focal_length @ 1..=9) => focal_length,
+                    _ => {
+                        return Err(ParseError::at_offset(
+                            input_string,
+                            input_offset + operation_position + 1,
+                            focal_length_text,
+                            "Invalid focal length",
+                        ))
+                    }
+                };
+                operations.push(Operation::Insert {
+                    label,
+                    focal_length,
+                });
+            }
+            _ => operations.push(Operation::Remove { label }),
+        }
+        input_offset += input.len() + 1;
+    }
+
+    Ok(Puzzle { operations })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> u64 {
+    let mut boxes: HashMap<u8, Vec<Lens>> = HashMap::new();
+
+    for operation in puzzle.operations {
+        match operation {
+            Operation::Insert {
+                label,
+                focal_length,
+            } => {
+                let box_number = evaluate_string_hash(&label);
+                add_lens(box_number, label, focal_length, &mut boxes)
+            }
+            Operation::Remove { label } => {
+                let box_number = evaluate_string_hash(&label);
+                remove_lens(box_number, label, &mut boxes)
+            }
+        }
+    }
+
+    calculate_focusing_power(&boxes)
+}
+
+pub fn solve(input_string: &str) -> Result<u64, ParseError> {
+    parse(input_string).map(solve_puzzle)
+}
+
+pub fn describe(focusing_power: u64) -> String {
+    format!("Focusing power: {}", focusing_power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 145);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 241094);
+    }
+
+    #[test]
+    fn it_evaluates_example_1() {
+        assert_eq!(evaluate_string_hash("rn=1"), 30);
+    }
+
+    #[test]
+    fn it_evaluates_example_2() {
+        assert_eq!(evaluate_string_hash("cm-"), 253);
+    }
+
+    #[test]
+    fn it_evaluates_example_3() {
+        assert_eq!(evaluate_string_hash("qp=3"), 97);
+    }
+
+    #[test]
+    fn it_evaluates_example_4() {
+        assert_eq!(evaluate_string_hash("ot=7"), 231);
+    }
+
+    #[test]
+    fn it_reports_invalid_focal_lengths() {
+        let error = solve("rn=1,cm-,qp=x3,cm-").unwrap_err();
+        assert_eq!(error, ParseError::new(0, 12, "x3", "Invalid focal length"));
+    }
+
+    #[test]
+    fn it_reports_missing_operations() {
+        let error = solve("rn=1,cm").unwrap_err();
+        assert_eq!(error, ParseError::new(0, 5, "cm", "No operation found"));
+    }
+
+    #[test]
+    fn it_reads_labels_that_are_not_ascii() {
+        // `é` hashes to box 121
+        assert_eq!(solve("é=1").unwrap(), 122);
+        let error = solve("é=x").unwrap_err();
+        assert_eq!(error, ParseError::new(0, 2, "x", "Invalid focal length"));
+    }
+}
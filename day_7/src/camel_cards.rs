@@ -0,0 +1,836 @@
+use crate::rules::RuleSet;
+use aoc_common::error::words;
+use aoc_common::ParseError;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A card under the rules it was read by. Cards are ordered by their
+/// strength alone, which is how tied hands are broken.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Card {
+    strength: usize,
+    symbol: char,
+    wild: bool,
+}
+
+impl Card {
+    pub(crate) fn from_char(c: char, rules: &RuleSet) -> Option<Card> {
+        Some(Card {
+            strength: rules.strength(c)?,
+            symbol: c,
+            wild: rules.is_wild(c),
+        })
+    }
+
+    fn symbol(&self) -> char {
+        self.symbol
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// The kind of hand, declared from weakest to strongest.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Every type, from weakest to strongest.
+    pub const ALL: [HandType; 7] = [
+        HandType::HighCard,
+        HandType::OnePair,
+        HandType::TwoPair,
+        HandType::ThreeOfAKind,
+        HandType::FullHouse,
+        HandType::FourOfAKind,
+        HandType::FiveOfAKind,
+    ];
+
+    /// The type made by groups of matching cards, given largest first.
+    fn from_groups(groups: &[usize]) -> HandType {
+        let largest = groups.first().copied().unwrap_or(0);
+        let second = groups.get(1).copied().unwrap_or(0);
+
+        match (largest, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+/// How a hand's type was worked out. The hand's cards are counted into
+/// groups of matching cards, and its jokers then join the largest group,
+/// since that always makes the strongest type.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Classification {
+    /// The sizes of the groups of matching cards other than jokers, largest
+    /// first.
+    groups: Vec<usize>,
+    /// The type the hand makes without its jokers.
+    raw_type: HandType,
+    jokers: usize,
+    /// The card the jokers copied, if there was anything for them to copy.
+    stand_in: Option<Card>,
+    pub(crate) hand_type: HandType,
+}
+
+impl Classification {
+    pub(crate) fn of(cards: &[Card]) -> Classification {
+        let mut histogram: BTreeMap<&Card, usize> = BTreeMap::new();
+        for card in cards.iter().filter(|card| !card.wild) {
+            *histogram.entry(card).or_default() += 1;
+        }
+        let jokers = cards.iter().filter(|card| card.wild).count();
+
+        // Largest group first, with the stronger card first among groups of
+        // the same size so that the jokers' stand-in is always the same
+        let mut counts: Vec<(usize, &Card)> = histogram
+            .into_iter()
+            .map(|(card, count)| (count, card))
+            .collect();
+        counts.sort_by(|a, b| b.cmp(a));
+
+        let groups: Vec<usize> = counts.iter().map(|&(count, _)| count).collect();
+        let mut with_jokers = groups.clone();
+        match with_jokers.first_mut() {
+            Some(largest) => *largest += jokers,
+            None if jokers > 0 => with_jokers.push(jokers),
+            None => {}
+        }
+
+        Classification {
+            raw_type: HandType::from_groups(&groups),
+            hand_type: HandType::from_groups(&with_jokers),
+            stand_in: counts
+                .first()
+                .filter(|_| jokers > 0)
+                .map(|&(_, card)| card.clone()),
+            groups,
+            jokers,
+        }
+    }
+}
+
+impl fmt::Display for Classification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let groups: Vec<String> = self.groups.iter().map(usize::to_string).collect();
+        let plural = if self.jokers == 1 { "joker" } else { "jokers" };
+        match &self.stand_in {
+            _ if self.jokers == 0 => write!(f, "{} ({})", self.hand_type, groups.join("+")),
+            Some(card) => write!(
+                f,
+                "{} ({}) with {} {} as {} makes {}",
+                self.raw_type,
+                groups.join("+"),
+                self.jokers,
+                plural,
+                card,
+                self.hand_type
+            ),
+            None => write!(f, "{} {} make {}", self.jokers, plural, self.hand_type),
+        }
+    }
+}
+
+/// A hand and its bid. Hands are ordered by strength alone: first by their
+/// type, and then card by card from the first, so two hands holding the
+/// same cards in the same order tie whatever they bid.
+struct Hand {
+    /// The cards as they were written in the input.
+    text: String,
+    classification: Classification,
+    cards: Vec<Card>,
+    bid: u32,
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Hand) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Hand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Hand) -> Ordering {
+        self.compare(other).0
+    }
+}
+
+/// What settles a comparison between two hands.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Decider {
+    /// One hand makes a stronger type than the other.
+    Type,
+    /// The hands make the same type, and the stronger card at this position,
+    /// counting from 0, is the first to differ.
+    Card(usize),
+    /// The hands hold the same cards in the same order.
+    Tie,
+}
+
+impl Hand {
+    fn from_str(s: &str, line: usize, rules: &RuleSet) -> Result<Hand, ParseError> {
+        let mut words = words(s);
+        let (cards_column, cards_text) = words
+            .next()
+            .ok_or_else(|| ParseError::new(line, 0, "", "Expected a hand"))?;
+        let mut hand = Hand::from_cards(cards_text, line, cards_column, rules)?;
+
+        let bid_column = cards_column + hand.cards.len();
+        let (bid_column, bid_text) = words.next().unwrap_or((bid_column, ""));
+        hand.bid = bid_text
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(line, bid_column, bid_text, "Invalid bid"))?;
+        if let Some((column, text)) = words.next() {
+            return Err(ParseError::new(line, column, text, "Unexpected text"));
+        }
+
+        Ok(hand)
+    }
+
+    /// Reads a hand from its cards alone, leaving it without a bid.
+    fn from_cards(
+        text: &str,
+        line: usize,
+        column: usize,
+        rules: &RuleSet,
+    ) -> Result<Hand, ParseError> {
+        let cards = Hand::str_to_cards(text, line, column, rules)?;
+        Ok(Hand {
+            text: text.to_string(),
+            classification: Classification::of(&cards),
+            cards,
+            bid: 0,
+        })
+    }
+
+    /// Orders two hands, first by their type and then card by card from the
+    /// first, and says which of those settled it. This is the one ordering
+    /// used both to rank a game and to explain a head to head.
+    fn compare(&self, other: &Hand) -> (Ordering, Decider) {
+        let by_type = self
+            .classification
+            .hand_type
+            .cmp(&other.classification.hand_type);
+        if by_type != Ordering::Equal {
+            return (by_type, Decider::Type);
+        }
+        let differing = self
+            .cards
+            .iter()
+            .zip(&other.cards)
+            .position(|(card, other_card)| card != other_card);
+        match differing {
+            Some(position) => (
+                self.cards[position].cmp(&other.cards[position]),
+                Decider::Card(position),
+            ),
+            None => (self.cards.len().cmp(&other.cards.len()), Decider::Tie),
+        }
+    }
+
+    /// Checks that the hand holds as many cards as the others in its game.
+    fn check_size(&self, size: usize, line: &str, line_number: usize) -> Result<(), ParseError> {
+        if self.cards.len() == size {
+            return Ok(());
+        }
+        let (column, text) = words(line).next().unwrap();
+        let message = format!("Expected {} cards", size);
+        Err(ParseError::new(line_number, column, text, message))
+    }
+
+    fn str_to_cards(
+        s: &str,
+        line: usize,
+        column: usize,
+        rules: &RuleSet,
+    ) -> Result<Vec<Card>, ParseError> {
+        s.chars()
+            .enumerate()
+            .map(|(offset, c)| {
+                Card::from_char(c, rules)
+                    .ok_or_else(|| ParseError::new(line, column + offset, c, "Unexpected card"))
+            })
+            .collect()
+    }
+}
+
+/// The hands dealt in a game, each with its bid. Every hand in a game holds
+/// as many cards as the first one.
+pub struct Game {
+    hands: Vec<Hand>,
+}
+
+impl Game {
+    pub fn parse(input: &str, rules: &RuleSet) -> Result<Game, ParseError> {
+        let mut hands: Vec<Hand> = Vec::new();
+
+        for (line_number, line) in input.lines().enumerate() {
+            let hand = Hand::from_str(line, line_number, rules)?;
+            if let Some(first) = hands.first() {
+                hand.check_size(first.cards.len(), line, line_number)?;
+            }
+            hands.push(hand);
+        }
+
+        Ok(Game { hands })
+    }
+
+    /// Ranks every hand, weakest first, so that the weakest hand has rank 1.
+    pub fn ranked(self) -> Vec<RankedHand> {
+        let mut hands = self.hands;
+        hands.sort();
+
+        hands
+            .into_iter()
+            .enumerate()
+            .map(|(index, hand)| RankedHand {
+                rank: index as u64 + 1,
+                hand,
+            })
+            .collect()
+    }
+
+    /// Ranks every hand and adds up each bid multiplied by the rank of its
+    /// hand.
+    pub fn total_winnings(self) -> u128 {
+        self.ranked().iter().map(RankedHand::winnings).sum()
+    }
+}
+
+/// Hands given by their cards alone, played against each other.
+pub struct Showdown {
+    hands: Vec<Hand>,
+}
+
+/// One hand's place in a showdown.
+pub struct Standing {
+    pub cards: String,
+    pub hand_type: HandType,
+    /// What settled how this hand compares with the next weaker one, if
+    /// there is one.
+    pub over_next: Option<Decider>,
+}
+
+impl Showdown {
+    /// Reads each hand from its cards, reporting errors against the position
+    /// of the hand in the list as if it were a line.
+    pub fn parse(hands: &[&str], rules: &RuleSet) -> Result<Showdown, ParseError> {
+        let mut parsed: Vec<Hand> = Vec::new();
+
+        for (index, text) in hands.iter().enumerate() {
+            let mut words = words(text);
+            let (column, cards) = words
+                .next()
+                .ok_or_else(|| ParseError::new(index, 0, "", "Expected a hand"))?;
+            if let Some((column, extra)) = words.next() {
+                return Err(ParseError::new(index, column, extra, "Unexpected text"));
+            }
+            let hand = Hand::from_cards(cards, index, column, rules)?;
+            if let Some(first) = parsed.first() {
+                hand.check_size(first.cards.len(), text, index)?;
+            }
+            parsed.push(hand);
+        }
+
+        Ok(Showdown { hands: parsed })
+    }
+
+    /// The hands from the strongest to the weakest, with hands that tie kept
+    /// in the order they were given.
+    pub fn standings(self) -> Vec<Standing> {
+        let mut hands = self.hands;
+        hands.sort_by(|a, b| b.cmp(a));
+
+        let deciders: Vec<Option<Decider>> = hands
+            .windows(2)
+            .map(|pair| Some(pair[0].compare(&pair[1]).1))
+            .chain([None])
+            .collect();
+        hands
+            .into_iter()
+            .zip(deciders)
+            .map(|(hand, over_next)| Standing {
+                cards: hand.text,
+                hand_type: hand.classification.hand_type,
+                over_next,
+            })
+            .collect()
+    }
+}
+
+/// What the hands holding the same cards add up to in a `Tally`.
+#[derive(Default)]
+struct Tied {
+    hands: u64,
+    bids: u128,
+    /// Each bid multiplied by how many of these hands were dealt before it.
+    bids_by_deal: u128,
+}
+
+/// Totals up the winnings of a game a hand at a time, for games too large to
+/// hold every hand in memory. Hands holding the same cards tie, so the tally
+/// keeps one entry for each distinct hand rather than one for each hand
+/// dealt, which bounds its memory by the number of possible hands however
+/// long the game runs. Tied hands are ranked in the order they were dealt,
+/// just as `Game` ranks them.
+pub struct Tally<'a> {
+    rules: &'a RuleSet,
+    hands: BTreeMap<(HandType, Vec<Card>), Tied>,
+    hand_size: Option<usize>,
+    lines: usize,
+}
+
+impl<'a> Tally<'a> {
+    pub fn new(rules: &'a RuleSet) -> Tally<'a> {
+        Tally {
+            rules,
+            hands: BTreeMap::new(),
+            hand_size: None,
+            lines: 0,
+        }
+    }
+
+    /// Adds the hand dealt on the next line of the game.
+    pub fn add(&mut self, line: &str) -> Result<(), ParseError> {
+        let line_number = self.lines;
+        self.lines += 1;
+
+        let hand = Hand::from_str(line, line_number, self.rules)?;
+        let size = *self.hand_size.get_or_insert(hand.cards.len());
+        hand.check_size(size, line, line_number)?;
+
+        let tied = self
+            .hands
+            .entry((hand.classification.hand_type, hand.cards))
+            .or_default();
+        tied.bids_by_deal += tied.hands as u128 * hand.bid as u128;
+        tied.hands += 1;
+        tied.bids += hand.bid as u128;
+        Ok(())
+    }
+
+    /// Adds up each bid multiplied by the rank of its hand, which is one more
+    /// than the number of hands that rank below it.
+    pub fn total_winnings(&self) -> u128 {
+        let mut below: u64 = 0;
+        let mut total_winnings: u128 = 0;
+
+        for tied in self.hands.values() {
+            total_winnings += (below as u128 + 1) * tied.bids + tied.bids_by_deal;
+            below += tied.hands;
+        }
+
+        total_winnings
+    }
+}
+
+/// How many of the hands fell into each type, from the weakest type to the
+/// strongest, including the types no hand made.
+pub fn type_counts(hands: &[RankedHand]) -> Vec<(HandType, usize)> {
+    HandType::ALL
+        .into_iter()
+        .map(|hand_type| {
+            let count = hands
+                .iter()
+                .filter(|hand| hand.hand_type() == hand_type)
+                .count();
+            (hand_type, count)
+        })
+        .collect()
+}
+
+/// A hand together with the rank it was given in its game.
+pub struct RankedHand {
+    pub rank: u64,
+    hand: Hand,
+}
+
+impl RankedHand {
+    pub fn cards(&self) -> &str {
+        &self.hand.text
+    }
+
+    /// The type the hand makes without counting its jokers as anything.
+    pub fn raw_type(&self) -> HandType {
+        self.hand.classification.raw_type
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        self.hand.classification.hand_type
+    }
+
+    pub fn jokers(&self) -> usize {
+        self.hand.classification.jokers
+    }
+
+    /// The card the hand's jokers stood in for, or `None` when it has no
+    /// jokers or nothing but jokers.
+    pub fn stand_in(&self) -> Option<char> {
+        self.hand.classification.stand_in.as_ref().map(Card::symbol)
+    }
+
+    /// How the hand's type was worked out, in words.
+    pub fn explanation(&self) -> String {
+        self.hand.classification.to_string()
+    }
+
+    pub fn bid(&self) -> u32 {
+        self.hand.bid
+    }
+
+    /// What the hand adds to the game's total winnings, which can be more
+    /// than a `u64` holds once a game has billions of hands.
+    pub fn winnings(&self) -> u128 {
+        self.hand.bid as u128 * self.rank as u128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{JOKERS_WILD, STANDARD};
+
+    #[test]
+    fn it_treats_the_joker_symbol_as_a_joker() {
+        assert!(!Card::from_char('J', &STANDARD).unwrap().wild);
+        assert!(Card::from_char('J', &JOKERS_WILD).unwrap().wild);
+    }
+
+    #[test]
+    fn it_only_makes_jokers_wild_under_jokers_wild_rules() {
+        let standard = Hand::from_str("KTJJT 220", 0, &STANDARD).unwrap();
+        let jokers_wild = Hand::from_str("KTJJT 220", 0, &JOKERS_WILD).unwrap();
+        assert_eq!(standard.classification.hand_type, HandType::TwoPair);
+        assert_eq!(jokers_wild.classification.hand_type, HandType::FourOfAKind);
+    }
+
+    #[test]
+    fn it_ranks_jokers_below_every_other_card() {
+        let card = |c: char| Card::from_char(c, &JOKERS_WILD).unwrap();
+        assert!(card('J') < card('2'));
+        assert!(card('2') < card('T'));
+        assert!(card('T') < card('A'));
+    }
+
+    #[test]
+    fn it_orders_hands_by_type_before_cards() {
+        let four_of_a_kind = Hand::from_str("2AAAA 1", 0, &STANDARD).unwrap();
+        let full_house = Hand::from_str("AAAKK 1", 0, &STANDARD).unwrap();
+        assert!(full_house < four_of_a_kind);
+    }
+
+    #[test]
+    fn it_breaks_ties_on_the_first_differing_card() {
+        let first = Hand::from_str("33332 1", 0, &STANDARD).unwrap();
+        let second = Hand::from_str("2AAAA 1", 0, &STANDARD).unwrap();
+        assert!(second < first);
+
+        let first = Hand::from_str("77888 1", 0, &STANDARD).unwrap();
+        let second = Hand::from_str("77788 1", 0, &STANDARD).unwrap();
+        assert!(second < first);
+    }
+
+    #[test]
+    fn it_ties_hands_with_the_same_cards_whatever_their_bids() {
+        let first = Hand::from_str("KTJJT 1", 0, &JOKERS_WILD).unwrap();
+        let second = Hand::from_str("KTJJT 2", 0, &JOKERS_WILD).unwrap();
+        assert_eq!(first.cmp(&second), Ordering::Equal);
+    }
+
+    #[test]
+    fn it_infers_the_hand_size_from_the_input() {
+        let three = Hand::from_str("KKT 7", 0, &STANDARD).unwrap();
+        let seven = Hand::from_str("  AAKKQQ2   42", 0, &STANDARD).unwrap();
+        assert_eq!((three.cards.len(), three.bid), (3, 7));
+        assert_eq!((seven.cards.len(), seven.bid), (7, 42));
+    }
+
+    #[test]
+    fn it_classifies_hands_of_any_size() {
+        let hand_type = |s: &str, rules: &RuleSet| {
+            Hand::from_str(s, 0, rules)
+                .unwrap()
+                .classification
+                .hand_type
+        };
+        assert_eq!(hand_type("KKK 1", &STANDARD), HandType::ThreeOfAKind);
+        assert_eq!(hand_type("JJJ 1", &JOKERS_WILD), HandType::ThreeOfAKind);
+        assert_eq!(hand_type("AAKKQQ 1", &STANDARD), HandType::TwoPair);
+        assert_eq!(hand_type("AAKKJJ 1", &JOKERS_WILD), HandType::FourOfAKind);
+        assert_eq!(hand_type("AAAKKJ 1", &JOKERS_WILD), HandType::FourOfAKind);
+        assert_eq!(hand_type("AAAAKKK 1", &STANDARD), HandType::FourOfAKind);
+        assert_eq!(hand_type("AAAAAA2 1", &STANDARD), HandType::FiveOfAKind);
+    }
+
+    #[test]
+    fn it_reports_where_a_hand_fails_to_parse() {
+        let error = |s: &str| Hand::from_str(s, 3, &STANDARD).err().unwrap();
+        assert_eq!(
+            error("KKX7 1"),
+            ParseError::new(3, 2, 'X', "Unexpected card")
+        );
+        assert_eq!(
+            error(" KKT77 1x"),
+            ParseError::new(3, 7, "1x", "Invalid bid")
+        );
+        assert_eq!(error("KKT77"), ParseError::new(3, 5, "", "Invalid bid"));
+        assert_eq!(
+            error("KKT77 1 2"),
+            ParseError::new(3, 8, "2", "Unexpected text")
+        );
+    }
+
+    #[test]
+    fn it_rejects_games_with_hands_of_different_sizes() {
+        let error = Game::parse("KKT 1\nKKT7 2\n", &STANDARD).err().unwrap();
+        assert_eq!(error, ParseError::new(1, 0, "KKT7", "Expected 3 cards"));
+    }
+
+    #[test]
+    fn it_adds_jokers_to_the_largest_group() {
+        let cards = |s: &str| Hand::str_to_cards(s, 0, 0, &JOKERS_WILD).unwrap();
+        // Every way of filling a hand with jokers and three kinds of card
+        let kinds = cards("J23A");
+        for n in 0..kinds.len().pow(5) {
+            let hand: Vec<Card> = (0..5)
+                .map(|i| kinds[n / kinds.len().pow(i) % kinds.len()].clone())
+                .collect();
+            // The best type any choice of stand-ins for the jokers can make
+            let best = kinds[1..]
+                .iter()
+                .map(|stand_in| {
+                    let substituted: Vec<Card> = hand
+                        .iter()
+                        .map(|card| match card.wild {
+                            true => stand_in.clone(),
+                            false => card.clone(),
+                        })
+                        .collect();
+                    Classification::of(&substituted).hand_type
+                })
+                .max()
+                .unwrap();
+            assert_eq!(Classification::of(&hand).hand_type, best, "{:?}", hand);
+        }
+        assert_eq!(
+            Classification::of(&cards("JJJJJJ")).hand_type,
+            HandType::FiveOfAKind
+        );
+    }
+
+    #[test]
+    fn it_explains_how_the_type_was_made() {
+        let explain = |s: &str| {
+            Classification::of(&Hand::str_to_cards(s, 0, 0, &JOKERS_WILD).unwrap()).to_string()
+        };
+        assert_eq!(explain("32T3K"), "one pair (2+1+1+1)");
+        assert_eq!(
+            explain("KTJJT"),
+            "one pair (2+1) with 2 jokers as T makes four of a kind"
+        );
+        assert_eq!(
+            explain("QQQJA"),
+            "three of a kind (3+1) with 1 joker as Q makes four of a kind"
+        );
+        assert_eq!(explain("JJJJJ"), "5 jokers make five of a kind");
+    }
+
+    #[test]
+    fn it_ranks_the_weakest_hand_first() {
+        let game = Game::parse("KTJJT 220\n32T3K 765\nT55J5 684\n", &JOKERS_WILD).unwrap();
+        let ranked = game.ranked();
+        let table: Vec<(u64, &str, u128)> = ranked
+            .iter()
+            .map(|hand| (hand.rank, hand.cards(), hand.winnings()))
+            .collect();
+        assert_eq!(
+            table,
+            vec![(1, "32T3K", 765), (2, "T55J5", 1368), (3, "KTJJT", 660)]
+        );
+    }
+
+    #[test]
+    fn it_reports_what_the_jokers_stood_in_for() {
+        let ranked = Game::parse("KTJJT 1\nJJJJJ 2\n32T3K 3\n", &JOKERS_WILD)
+            .unwrap()
+            .ranked();
+        let jokers: Vec<(&str, HandType, usize, Option<char>, HandType)> = ranked
+            .iter()
+            .map(|hand| {
+                (
+                    hand.cards(),
+                    hand.raw_type(),
+                    hand.jokers(),
+                    hand.stand_in(),
+                    hand.hand_type(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            jokers,
+            vec![
+                ("32T3K", HandType::OnePair, 0, None, HandType::OnePair),
+                (
+                    "KTJJT",
+                    HandType::OnePair,
+                    2,
+                    Some('T'),
+                    HandType::FourOfAKind
+                ),
+                ("JJJJJ", HandType::HighCard, 5, None, HandType::FiveOfAKind),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_counts_the_hands_of_each_type() {
+        let ranked = Game::parse("KTJJT 1\n32T3K 2\nT55J5 3\n", &JOKERS_WILD)
+            .unwrap()
+            .ranked();
+        assert_eq!(
+            type_counts(&ranked),
+            vec![
+                (HandType::HighCard, 0),
+                (HandType::OnePair, 1),
+                (HandType::TwoPair, 0),
+                (HandType::ThreeOfAKind, 0),
+                (HandType::FullHouse, 0),
+                (HandType::FourOfAKind, 2),
+                (HandType::FiveOfAKind, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_plays_by_the_alphabet_and_wild_cards_of_its_rules() {
+        let rules = RuleSet::parse("name = regional\ncards = 2345*6789TJQKA\nwild = *").unwrap();
+        let hand = Hand::from_str("K*K*2 1", 0, &rules).unwrap();
+        assert_eq!(hand.classification.hand_type, HandType::FourOfAKind);
+        assert_eq!(hand.classification.stand_in.unwrap().symbol(), 'K');
+
+        let weaker = Hand::from_str("5KK*K 1", 0, &rules).unwrap();
+        let stronger = Hand::from_str("6KK*K 1", 0, &rules).unwrap();
+        assert!(weaker < stronger);
+        assert!(Hand::from_str("1KK*K 1", 0, &rules).is_err());
+    }
+
+    #[test]
+    fn it_tallies_the_same_winnings_as_ranking_every_hand() {
+        let input =
+            "32T3K 765\nKTJJT 220\nKK677 28\nKTJJT 5\nT55J5 684\n32T3K 1\nQQQJA 483\nKTJJT 70000\n";
+        for rules in [&STANDARD, &JOKERS_WILD] {
+            let mut tally = Tally::new(rules);
+            for line in input.lines() {
+                tally.add(line).unwrap();
+            }
+            let game = Game::parse(input, rules).unwrap();
+            assert_eq!(tally.total_winnings(), game.total_winnings());
+        }
+    }
+
+    #[test]
+    fn it_totals_winnings_beyond_a_u64() {
+        // 4000000000 × (1 + 2 + … + 200000) is just over 2⁶⁶
+        let input = "32T3K 4000000000\n".repeat(200_000);
+        let mut tally = Tally::new(&STANDARD);
+        for line in input.lines() {
+            tally.add(line).unwrap();
+        }
+        assert_eq!(tally.total_winnings(), 80000400000000000000);
+        let game = Game::parse(&input, &STANDARD).unwrap();
+        assert_eq!(game.total_winnings(), 80000400000000000000);
+    }
+
+    #[test]
+    fn it_reports_the_line_a_tally_fails_on() {
+        let mut tally = Tally::new(&STANDARD);
+        tally.add("32T3K 765").unwrap();
+        assert_eq!(
+            tally.add("32T3 765").unwrap_err(),
+            ParseError::new(1, 0, "32T3", "Expected 5 cards")
+        );
+    }
+
+    #[test]
+    fn it_explains_what_settles_each_comparison() {
+        let hand = |s: &str| Hand::from_cards(s, 0, 0, &STANDARD).unwrap();
+        assert_eq!(
+            hand("KTJJT").compare(&hand("32T3K")),
+            (Ordering::Greater, Decider::Type)
+        );
+        assert_eq!(
+            hand("KTJJT").compare(&hand("KK677")),
+            (Ordering::Less, Decider::Card(1))
+        );
+        assert_eq!(
+            hand("KTJJT").compare(&hand("KTJJT")),
+            (Ordering::Equal, Decider::Tie)
+        );
+    }
+
+    #[test]
+    fn it_lists_a_showdown_from_the_strongest_hand() {
+        let standings = Showdown::parse(&["KTJJT", "32T3K", "KK677", "KTJJT"], &JOKERS_WILD)
+            .unwrap()
+            .standings();
+        let table: Vec<(&str, HandType, Option<Decider>)> = standings
+            .iter()
+            .map(|standing| (&*standing.cards, standing.hand_type, standing.over_next))
+            .collect();
+        assert_eq!(
+            table,
+            vec![
+                ("KTJJT", HandType::FourOfAKind, Some(Decider::Tie)),
+                ("KTJJT", HandType::FourOfAKind, Some(Decider::Type)),
+                ("KK677", HandType::TwoPair, Some(Decider::Type)),
+                ("32T3K", HandType::OnePair, None),
+            ]
+        );
+        assert_eq!(
+            Showdown::parse(&["KTJJT", "KTJ"], &JOKERS_WILD)
+                .err()
+                .unwrap(),
+            ParseError::new(1, 0, "KTJ", "Expected 5 cards")
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::rules::STANDARD;
+use aoc_common::Rng;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// Roughly the number of hands in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// An input needs at least one hand, and there are only 13^5 distinct
+/// hands to deal.
+pub const SIZES: RangeInclusive<usize> = 1..=371_293;
+
+/// A list of `size` distinct hands, each with a bid.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    assert!(SIZES.contains(&size), "There are not that many hands");
+    let symbols: Vec<char> = STANDARD.cards.chars().collect();
+
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut lines: Vec<String> = Vec::new();
+    while lines.len() < size {
+        // Drawing from a smaller set of cards for some hands makes pairs and
+        // better hands as common as they are in the real inputs
+        let card_count = rng.between(2, symbols.len() as i64) as usize;
+        let mut cards = symbols.clone();
+        rng.shuffle(&mut cards);
+        let hand: String = (0..5).map(|_| *rng.choose(&cards[..card_count])).collect();
+        if seen.insert(hand.clone()) {
+            lines.push(format!("{} {}", hand, rng.between(1, 1000)));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 200);
+        assert_eq!(input.lines().count(), 200);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+
+    #[test]
+    fn it_can_deal_every_distinct_hand() {
+        assert_eq!(*SIZES.end(), STANDARD.cards.len().pow(5));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::camel_cards::{Card, Classification, HandType};
+use crate::rules::RuleSet;
+use aoc_common::ParseError;
+
+/// How many of the equally likely ways of dealing the unknown cards of a hand
+/// make each type. Camel cards has no deck, so every unknown card is any of
+/// the rules' cards with the same chance, whatever else is in the hand.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Distribution {
+    /// Every type from the weakest to the strongest, with how many deals
+    /// make it.
+    pub counts: Vec<(HandType, u64)>,
+    /// How many deals there are in all.
+    pub total: u64,
+}
+
+impl Distribution {
+    /// Works out the distribution of a hand of `hand_size` cards that is
+    /// known to hold the cards in `given`, in any order. Whitespace in
+    /// `given` is ignored, so `"KK J"` reads as two kings and a `J`.
+    pub fn of(rules: &RuleSet, hand_size: usize, given: &str) -> Result<Distribution, ParseError> {
+        let mut known: Vec<Card> = Vec::new();
+        for (column, c) in given.chars().enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+            let card = Card::from_char(c, rules)
+                .ok_or_else(|| ParseError::new(0, column, c, "Unexpected card"))?;
+            if known.len() == hand_size {
+                let message = format!("Expected at most {} cards", hand_size);
+                return Err(ParseError::new(0, column, c, message));
+            }
+            known.push(card);
+        }
+
+        let unknown = hand_size - known.len();
+        let deck: Vec<Card> = rules
+            .cards
+            .chars()
+            .map(|c| Card::from_char(c, rules).unwrap())
+            .collect();
+        let total = (deck.len() as u64)
+            .checked_pow(unknown as u32)
+            .ok_or_else(|| ParseError::new(0, 0, given, "Too many unknown cards to count"))?;
+
+        let mut counts: Vec<(HandType, u64)> = HandType::ALL
+            .into_iter()
+            .map(|hand_type| (hand_type, 0))
+            .collect();
+        let binomials = pascals_triangle(unknown);
+        deal(&deck, &binomials, unknown, &mut known, 1, &mut counts);
+
+        Ok(Distribution { counts, total })
+    }
+
+    pub fn probability(&self, hand_type: HandType) -> f64 {
+        let (_, count) = self.counts[hand_type as usize];
+        count as f64 / self.total as f64
+    }
+}
+
+/// `binomials[n][k]` is n choose k, for every n up to `size`.
+fn pascals_triangle(size: usize) -> Vec<Vec<u64>> {
+    let mut rows: Vec<Vec<u64>> = vec![vec![1]];
+    for n in 1..=size {
+        let previous = &rows[n - 1];
+        let mut row = vec![1; n + 1];
+        for k in 1..n {
+            row[k] = previous[k - 1] + previous[k];
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+/// Deals the `remaining` unknown cards as every multiset of cards from the
+/// deck rather than every sequence, since the order of the cards does not
+/// change a hand's type. Each multiset stands for as many sequences as
+/// there are ways of placing its cards among the unknown positions, which
+/// is the product of a binomial for each card.
+fn deal(
+    deck: &[Card],
+    binomials: &[Vec<u64>],
+    remaining: usize,
+    hand: &mut Vec<Card>,
+    ways: u64,
+    counts: &mut [(HandType, u64)],
+) {
+    let Some((card, rest)) = deck.split_first() else {
+        if remaining == 0 {
+            let hand_type = Classification::of(hand).hand_type;
+            counts[hand_type as usize].1 += ways;
+        }
+        return;
+    };
+
+    for copies in 0..=remaining {
+        hand.extend((0..copies).map(|_| card.clone()));
+        let ways = ways * binomials[remaining][copies];
+        deal(rest, binomials, remaining - copies, hand, ways, counts);
+        hand.truncate(hand.len() - copies);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{JOKERS_WILD, STANDARD};
+
+    fn counts(distribution: &Distribution) -> Vec<u64> {
+        distribution
+            .counts
+            .iter()
+            .map(|&(_, count)| count)
+            .collect()
+    }
+
+    #[test]
+    fn it_counts_every_five_card_hand() {
+        let distribution = Distribution::of(&STANDARD, 5, "").unwrap();
+        assert_eq!(distribution.total, 371293);
+        assert_eq!(
+            counts(&distribution),
+            vec![154440, 171600, 25740, 17160, 1560, 780, 13]
+        );
+        assert_eq!(
+            distribution.probability(HandType::FiveOfAKind),
+            1.0 / 28561.0
+        );
+    }
+
+    #[test]
+    fn it_makes_better_hands_more_likely_with_jokers() {
+        let standard = Distribution::of(&STANDARD, 5, "").unwrap();
+        let jokers_wild = Distribution::of(&JOKERS_WILD, 5, "").unwrap();
+        assert_eq!(counts(&jokers_wild).iter().sum::<u64>(), jokers_wild.total);
+        assert!(
+            jokers_wild.probability(HandType::HighCard) < standard.probability(HandType::HighCard)
+        );
+        assert!(
+            jokers_wild.probability(HandType::FiveOfAKind)
+                > standard.probability(HandType::FiveOfAKind)
+        );
+    }
+
+    #[test]
+    fn it_conditions_on_the_known_cards() {
+        let distribution = Distribution::of(&STANDARD, 5, "KK KK").unwrap();
+        assert_eq!(distribution.total, 13);
+        assert_eq!(counts(&distribution), vec![0, 0, 0, 0, 0, 12, 1]);
+
+        // Two unknown cards joining two kings and a joker make at least
+        // three of a kind
+        let distribution = Distribution::of(&JOKERS_WILD, 5, "KK J").unwrap();
+        assert_eq!(distribution.total, 169);
+        assert_eq!(counts(&distribution), vec![0, 0, 0, 110, 11, 44, 4]);
+    }
+
+    #[test]
+    fn it_rejects_cards_it_does_not_know_or_has_no_room_for() {
+        assert_eq!(
+            Distribution::of(&STANDARD, 5, "KX").unwrap_err(),
+            ParseError::new(0, 1, 'X', "Unexpected card")
+        );
+        assert_eq!(
+            Distribution::of(&STANDARD, 3, "KKKK").unwrap_err(),
+            ParseError::new(0, 3, 'K', "Expected at most 3 cards")
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::camel_cards::Game;
+use crate::rules::JOKERS_WILD;
+use aoc_common::ParseError;
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    Game::parse(input, &JOKERS_WILD)
+}
+
+pub fn solve_puzzle(game: Game) -> u128 {
+    game.total_winnings()
+}
+
+pub fn solve(input: &str) -> Result<u128, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(total_wininings: u128) -> String {
+    format!("Total wininings: {}", total_wininings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765\n\
+T55J5 684\n\
+KK677 28\n\
+KTJJT 220\n\
+QQQJA 483";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), 5905);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), 251421071);
+    }
+}
@@ -0,0 +1,175 @@
+use aoc_common::error::words;
+use aoc_common::ParseError;
+use std::borrow::Cow;
+
+/// The rules a game of camel cards is played by: which cards there are, how
+/// they rank against each other when breaking ties, and which of them are
+/// wild when classifying a hand.
+///
+/// Besides the built in rule sets, rules can be read from a small file of
+/// `key = value` settings, where `#` starts a comment:
+///
+/// ```text
+/// name = star-wild
+/// # From the weakest card to the strongest
+/// cards = *23456789TJQKA
+/// wild = *
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleSet {
+    pub name: Cow<'static, str>,
+    /// The symbol of every card, from the weakest to the strongest.
+    pub cards: Cow<'static, str>,
+    /// The symbols of the cards that are wild.
+    pub wild: Cow<'static, str>,
+}
+
+/// The rules of part 1, where `J` is a jack.
+pub const STANDARD: RuleSet = RuleSet {
+    name: Cow::Borrowed("standard"),
+    cards: Cow::Borrowed("23456789TJQKA"),
+    wild: Cow::Borrowed(""),
+};
+
+/// The rules of part 2, where `J` is a joker that is wild but ranks below
+/// every other card.
+pub const JOKERS_WILD: RuleSet = RuleSet {
+    name: Cow::Borrowed("jokers-wild"),
+    cards: Cow::Borrowed("J23456789TQKA"),
+    wild: Cow::Borrowed("J"),
+};
+
+pub const RULE_SETS: &[RuleSet] = &[STANDARD, JOKERS_WILD];
+
+impl RuleSet {
+    pub fn named(name: &str) -> Option<&'static RuleSet> {
+        RULE_SETS.iter().find(|rules| rules.name == name)
+    }
+
+    /// Reads rules from the settings in a rules file.
+    pub fn parse(input: &str) -> Result<RuleSet, ParseError> {
+        let mut name: Option<Setting> = None;
+        let mut cards: Option<Setting> = None;
+        let mut wild: Option<Setting> = None;
+
+        for (line_number, line) in input.lines().enumerate() {
+            let line = line.split('#').next().unwrap();
+            let Some((column, first_word)) = words(line).next() else {
+                continue;
+            };
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ParseError::new(
+                    line_number,
+                    column,
+                    first_word,
+                    "Expected `=`",
+                ));
+            };
+            let value_offset = line.len() - value.trim_start().len();
+            let setting = match key.trim() {
+                "name" => &mut name,
+                "cards" => &mut cards,
+                "wild" => &mut wild,
+                key => return Err(ParseError::new(line_number, column, key, "Unknown setting")),
+            };
+            *setting = Some(Setting {
+                line: line_number,
+                column: line[..value_offset].chars().count(),
+                value: value.trim().to_string(),
+            });
+        }
+
+        let name = name.ok_or_else(|| ParseError::new(0, 0, "", "Missing `name` setting"))?;
+        let cards = cards.ok_or_else(|| ParseError::new(0, 0, "", "Missing `cards` setting"))?;
+        if cards.value.is_empty() {
+            return Err(cards.error(0, "", "Expected at least one card"));
+        }
+        for (offset, c) in cards.value.chars().enumerate() {
+            if c.is_whitespace() {
+                return Err(cards.error(offset, c, "Unexpected space"));
+            }
+            if cards.value.chars().take(offset).any(|other| other == c) {
+                return Err(cards.error(offset, c, "Repeated card"));
+            }
+        }
+        let wild = match wild {
+            Some(wild) => {
+                let unknown = wild
+                    .value
+                    .chars()
+                    .enumerate()
+                    .find(|&(_, c)| !cards.value.contains(c));
+                if let Some((offset, c)) = unknown {
+                    return Err(wild.error(offset, c, "Unknown card"));
+                }
+                wild.value
+            }
+            None => String::new(),
+        };
+
+        Ok(RuleSet {
+            name: Cow::Owned(name.value),
+            cards: Cow::Owned(cards.value),
+            wild: Cow::Owned(wild),
+        })
+    }
+
+    /// How strong a card is, counting up from 0 for the weakest, or `None`
+    /// if there is no such card.
+    pub fn strength(&self, card: char) -> Option<usize> {
+        self.cards.chars().position(|c| c == card)
+    }
+
+    pub fn is_wild(&self, card: char) -> bool {
+        self.wild.contains(card)
+    }
+}
+
+/// A setting read from a rules file, with where its value was written.
+struct Setting {
+    line: usize,
+    column: usize,
+    value: String,
+}
+
+impl Setting {
+    /// An error about the character at `offset` in the setting's value.
+    fn error(&self, offset: usize, text: impl Into<String>, message: &str) -> ParseError {
+        ParseError::new(self.line, self.column + offset, text, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_rules_from_settings() {
+        let rules = RuleSet::parse("# A regional variant\nname = star-wild\ncards = *23456789TJQKA\nwild = *  # the star\n").unwrap();
+        assert_eq!(rules.name, "star-wild");
+        assert_eq!(rules.strength('*'), Some(0));
+        assert_eq!(rules.strength('A'), Some(13));
+        assert!(rules.is_wild('*'));
+        assert!(!rules.is_wild('J'));
+    }
+
+    #[test]
+    fn it_reports_mistakes_in_the_settings() {
+        assert_eq!(
+            RuleSet::parse("name = x\ncards = 23A3").unwrap_err(),
+            ParseError::new(1, 11, '3', "Repeated card")
+        );
+        assert_eq!(
+            RuleSet::parse("name = x\ncards = 23A\nwild = 2J").unwrap_err(),
+            ParseError::new(2, 8, 'J', "Unknown card")
+        );
+        assert_eq!(
+            RuleSet::parse("name = x\ncolour = red").unwrap_err(),
+            ParseError::new(1, 0, "colour", "Unknown setting")
+        );
+        assert_eq!(
+            RuleSet::parse("name = x").unwrap_err(),
+            ParseError::new(0, 0, "", "Missing `cards` setting")
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::Input;
+use day_9::part1_concurrent::{describe, parse, solve_concurrent_mutex, solve_linear};
+use std::time;
+
+fn main() {
+    let input = Input::from_args_or(day_9::INPUT_PATH)
+        .read()
+        .expect("Failed to read input");
+    let puzzle = parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.render(&input));
+        std::process::exit(1);
+    });
+
+    let start_time = time::SystemTime::now();
+    let next_values_sum = solve_linear(&puzzle);
+    println!("{}", describe(next_values_sum));
+    println!("Time elapsed: {:?}", start_time.elapsed().unwrap());
+
+    let start_time = time::SystemTime::now();
+    let next_values_sum = solve_concurrent_mutex(puzzle);
+    println!("{}", describe(next_values_sum));
+    println!("Time elapsed: {:?}", start_time.elapsed().unwrap());
+}
@@ -0,0 +1,15 @@
+use aoc_common::Input;
+use day_9::part2::describe;
+
+fn main() {
+    let input = Input::from_args_or(day_9::INPUT_PATH)
+        .read()
+        .expect("Failed to read input");
+    match day_9::solve_part2(&input) {
+        Ok(answer) => println!("{}", describe(answer)),
+        Err(error) => {
+            eprintln!("{}", error.render(&input));
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use aoc_common::Rng;
+use std::ops::RangeInclusive;
+
+/// The number of sequences in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 200;
+
+/// An input needs at least one sequence.
+pub const SIZES: RangeInclusive<usize> = 1..=usize::MAX;
+
+/// How many readings each sequence has, as in the real inputs.
+const SEQUENCE_LENGTH: i64 = 21;
+
+/// `size` sequences of readings, each following a polynomial of degree at
+/// most five with small integer coefficients so that every value, and the
+/// values either side of the sequence, fit comfortably in an `i32`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for _ in 0..size {
+        let degree = rng.between(0, 5);
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.between(-3, 3)).collect();
+        let first_x = rng.between(-5, 5);
+
+        let values: Vec<String> = (first_x..first_x + SEQUENCE_LENGTH)
+            .map(|x| {
+                // Horner's method, starting from the highest power
+                let value = coefficients.iter().rev().fold(0, |total, c| total * x + c);
+                value.to_string()
+            })
+            .collect();
+        lines.push(values.join(" "));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_generates_inputs_that_solve() {
+        let input = generate(&mut Rng::new(7), 50);
+        assert_eq!(input.lines().count(), 50);
+        assert!(crate::solve_part1(&input).is_ok());
+        assert!(crate::solve_part2(&input).is_ok());
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::error::words;
+use aoc_common::ParseError;
+use sequence::DifferenceTable;
+use std::str::FromStr;
+
+pub mod generate;
+pub mod part1;
+pub mod part1_closed_form;
+pub mod part1_concurrent;
+pub mod part2;
+pub mod part2_closed_form;
+pub mod polynomial;
+pub mod sequence;
+
+pub use part1::solve as solve_part1;
+pub use part2::solve as solve_part2;
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// Parses one line of whitespace separated readings.
+pub fn parse_values<T: FromStr>(line: &str, line_number: usize) -> Result<Vec<T>, ParseError> {
+    words(line)
+        .map(|(column, word)| {
+            word.parse::<T>()
+                .map_err(|_| ParseError::new(line_number, column, word, "Invalid value"))
+        })
+        .collect()
+}
+
+/// Parses one sequence of readings per line and works out each one's table
+/// of differences, reporting a sequence that cannot be extrapolated against
+/// its whole line.
+pub fn parse_sequences(input: &str) -> Result<Vec<DifferenceTable>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| {
+            let values = parse_values::<i64>(line, line_number)?;
+            DifferenceTable::new(&values)
+                .map_err(|error| ParseError::new(line_number, 0, line, error.to_string()))
+        })
+        .collect()
+}
+
+/// Parses one sequence of readings per line without keeping their
+/// differences, reporting a sequence that cannot be extrapolated against its
+/// whole line just as `parse_sequences` does.
+pub fn parse_readings(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| {
+            let values = parse_values::<i64>(line, line_number)?;
+            sequence::check(&values)
+                .map_err(|error| ParseError::new(line_number, 0, line, error.to_string()))?;
+            Ok(values)
+        })
+        .collect()
+}
@@ -0,0 +1,62 @@
+use crate::parse_sequences;
+use crate::sequence::DifferenceTable;
+use aoc_common::ParseError;
+use num_bigint::BigInt;
+
+/// The sequences of readings in the puzzle input, each with its table of
+/// differences.
+pub struct Puzzle {
+    sequences: Vec<DifferenceTable>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let sequences = parse_sequences(input)?;
+
+    Ok(Puzzle { sequences })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> BigInt {
+    let mut next_values_sum = BigInt::from(0);
+
+    for sequence in puzzle.sequences {
+        next_values_sum += sequence.next_value();
+    }
+
+    next_values_sum
+}
+
+pub fn solve(input: &str) -> Result<BigInt, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(next_values_sum: BigInt) -> String {
+    format!("Sum of next values: {}", next_values_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15\n\
+1 3 6 10 15 21\n\
+10 13 16 21 30 45";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), BigInt::from(114));
+    }
+
+    #[test]
+    fn it_predicts_past_the_largest_i64() {
+        let input = "9223372036854775805 9223372036854775806 9223372036854775807";
+        assert_eq!(solve(input).unwrap(), BigInt::from(i64::MAX) + 1);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), BigInt::from(1798691765));
+    }
+}
@@ -0,0 +1,75 @@
+// Works out each next value straight from the readings with binomial
+// weights instead of building a table of differences, see
+// `sequence::next_by_weights`.
+
+use crate::parse_readings;
+use crate::sequence::next_by_weights;
+use aoc_common::ParseError;
+use num_bigint::BigInt;
+
+pub use crate::part1::describe;
+
+/// The sequences of readings in the puzzle input.
+pub struct Puzzle {
+    sequences: Vec<Vec<i64>>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let sequences = parse_readings(input)?;
+
+    Ok(Puzzle { sequences })
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> BigInt {
+    puzzle
+        .sequences
+        .iter()
+        .map(|values| next_by_weights(values))
+        .sum()
+}
+
+pub fn solve(input: &str) -> Result<BigInt, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15\n\
+1 3 6 10 15 21\n\
+10 13 16 21 30 45";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), BigInt::from(114));
+    }
+
+    #[test]
+    fn it_agrees_with_the_difference_tables() {
+        let input = crate::generate::generate(&mut aoc_common::Rng::new(1), 50);
+        assert_eq!(solve(&input).unwrap(), crate::part1::solve(&input).unwrap());
+    }
+
+    #[test]
+    fn it_rejects_what_the_difference_tables_reject() {
+        for input in ["1 2", "1 2 4", "0 1 0", "1 2 3\n\n4 5 6"] {
+            let error = solve(input).unwrap_err();
+            assert_eq!(error, crate::part1::solve(input).unwrap_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn it_predicts_past_the_largest_i64() {
+        let input = "9223372036854775805 9223372036854775806 9223372036854775807";
+        assert_eq!(solve(input).unwrap(), BigInt::from(i64::MAX) + 1);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), BigInt::from(1798691765));
+    }
+}
@@ -0,0 +1,104 @@
+// I wrote this implementation of the solution for today as a learning exercise
+// to get some experience with how Rust handles concurrency.
+//
+// In reality this is not a good use case for concurrency because the threads
+// are all doing the same thing and they all need to access the same data.
+// This means that the threads will spend most of their time waiting for the
+// mutex to unlock.
+// This would be less of an issue if the computation was more complex so that
+// each thread took longer to complete, since the ratio of computation time to
+// wait time would be higher.
+
+use crate::parse_sequences;
+use crate::sequence::DifferenceTable;
+use aoc_common::ParseError;
+use num_bigint::BigInt;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// The sequences of readings in the puzzle input, each with its table of
+/// differences.
+pub struct Puzzle {
+    sequences: Vec<DifferenceTable>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let sequences = parse_sequences(input)?;
+
+    Ok(Puzzle { sequences })
+}
+
+pub fn solve_linear(puzzle: &Puzzle) -> BigInt {
+    let mut next_values_sum = BigInt::from(0);
+    for sequence in &puzzle.sequences {
+        let next_value = sequence.next_value();
+        next_values_sum += next_value;
+    }
+    next_values_sum
+}
+
+pub fn solve_concurrent_mutex(puzzle: Puzzle) -> BigInt {
+    // This is a concurrent implementation of the linear solution
+    // It uses a mutex to lock the sum of the next values
+    // ... and it takes about twice as long as the linear solution
+    let next_values_sum: Arc<Mutex<BigInt>> = Arc::new(Mutex::new(BigInt::from(0)));
+    let mut threads = Vec::new();
+
+    for sequence in puzzle.sequences {
+        let next_values_sum = Arc::clone(&next_values_sum);
+        let thread = thread::spawn(move || {
+            let next_value = sequence.next_value();
+            let mut sum = next_values_sum.lock().unwrap();
+            *sum += next_value;
+        });
+
+        threads.push(thread);
+    }
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let x = next_values_sum.lock().unwrap().clone();
+    x
+}
+
+pub fn solve_puzzle(puzzle: Puzzle) -> BigInt {
+    solve_concurrent_mutex(puzzle)
+}
+
+pub fn solve(input: &str) -> Result<BigInt, ParseError> {
+    parse(input).map(solve_puzzle)
+}
+
+pub fn describe(next_values_sum: BigInt) -> String {
+    format!("Sum of next values: {}", next_values_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15\n\
+1 3 6 10 15 21\n\
+10 13 16 21 30 45";
+
+    #[test]
+    fn it_solves_the_example() {
+        assert_eq!(solve(EXAMPLE).unwrap(), BigInt::from(114));
+    }
+
+    #[test]
+    fn it_predicts_past_the_largest_i64() {
+        let input = "9223372036854775805 9223372036854775806 9223372036854775807";
+        assert_eq!(solve(input).unwrap(), BigInt::from(i64::MAX) + 1);
+    }
+
+    #[test]
+    #[ignore = "reads the full puzzle input"]
+    fn it_solves_the_puzzle_input() {
+        let input = std::fs::read_to_string(crate::INPUT_PATH).unwrap();
+        assert_eq!(solve(&input).unwrap(), BigInt::from(1798691765));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::sequence::DifferenceTable;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt;
+
+/// The polynomial a sequence of readings follows, taking the first reading
+/// to be at `x = 0`, the next at `x = 1` and so on.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Polynomial {
+    /// The coefficient of each power of `x`, from the constant term up.
+    coefficients: Vec<BigRational>,
+}
+
+/// Multiplies a polynomial by `x - root`.
+fn times_linear(coefficients: &[BigRational], root: &BigRational) -> Vec<BigRational> {
+    let mut product = vec![BigRational::zero(); coefficients.len() + 1];
+    for (power, coefficient) in coefficients.iter().enumerate() {
+        product[power + 1] += coefficient;
+        product[power] -= coefficient * root;
+    }
+    product
+}
+
+impl Polynomial {
+    /// Works out the polynomial from a sequence's table of differences. By
+    /// Newton's forward difference formula it is the sum of the first value
+    /// of each row `j` times `x` choose `j`, so the coefficients are exact
+    /// fractions with at most `j!` below the line.
+    pub fn fit(table: &DifferenceTable) -> Polynomial {
+        Polynomial::from_leading_differences(table.leading_differences())
+    }
+
+    /// Works out the lowest degree polynomial through some readings, which
+    /// there always is, even when the table of differences would reject
+    /// them for running out of readings before the differences became
+    /// constant. There must be at least one reading.
+    pub fn through(values: &[i64]) -> Polynomial {
+        let mut row: Vec<BigInt> = values.iter().map(|&value| BigInt::from(value)).collect();
+        let mut leading = Vec::new();
+        while let Some(first) = row.first() {
+            leading.push(first.clone());
+            if row.windows(2).all(|pair| pair[0] == pair[1]) {
+                break;
+            }
+            row = row.windows(2).map(|pair| &pair[1] - &pair[0]).collect();
+        }
+        Polynomial::from_leading_differences(leading)
+    }
+
+    fn from_leading_differences(leading_differences: Vec<BigInt>) -> Polynomial {
+        let mut coefficients = vec![BigRational::zero(); leading_differences.len()];
+        // The coefficients of `x` choose `j`, starting from `x` choose 0
+        let mut binomial = vec![BigRational::one()];
+
+        for (j, leading) in leading_differences.into_iter().enumerate() {
+            if j > 0 {
+                let root = BigRational::from_integer(BigInt::from(j - 1));
+                let divisor = BigRational::from_integer(BigInt::from(j));
+                binomial = times_linear(&binomial, &root)
+                    .into_iter()
+                    .map(|coefficient| coefficient / &divisor)
+                    .collect();
+            }
+            let leading = BigRational::from_integer(leading);
+            for (total, coefficient) in coefficients.iter_mut().zip(&binomial) {
+                *total += &leading * coefficient;
+            }
+        }
+
+        Polynomial { coefficients }
+    }
+
+    /// The coefficient of each power of `x`, from the constant term up.
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// The value of the polynomial at `x`, by Horner's method.
+    pub fn at(&self, x: i64) -> BigRational {
+        let x = BigRational::from_integer(BigInt::from(x));
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |total, coefficient| {
+                total * &x + coefficient
+            })
+    }
+
+    /// How far the polynomial misses each reading by, which is zero for
+    /// every reading when it really does generate the sequence.
+    pub fn residuals(&self, values: &[i64]) -> Vec<BigRational> {
+        values
+            .iter()
+            .zip(0..)
+            .map(|(&value, x)| self.at(x) - BigRational::from_integer(BigInt::from(value)))
+            .collect()
+    }
+}
+
+/// Writes the polynomial from its highest power down, as in
+/// `1/2 x^2 + 3/2 x + 1`, leaving out terms whose coefficient is zero.
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .peekable();
+        if terms.peek().is_none() {
+            return write!(f, "0");
+        }
+
+        for (written, (power, coefficient)) in terms.enumerate() {
+            let size = coefficient.abs();
+            match (written, coefficient.is_negative()) {
+                (0, false) => {}
+                (0, true) => write!(f, "-")?,
+                (_, false) => write!(f, " + ")?,
+                (_, true) => write!(f, " - ")?,
+            }
+            match power {
+                0 => write!(f, "{}", size)?,
+                _ if size.is_one() => write!(f, "x")?,
+                _ => write!(f, "{} x", size)?,
+            }
+            if power > 1 {
+                write!(f, "^{}", power)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fit(values: &[i64]) -> Polynomial {
+        Polynomial::fit(&DifferenceTable::new(values).unwrap())
+    }
+
+    fn rational(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    #[test]
+    fn it_fits_the_examples() {
+        assert_eq!(fit(&[0, 3, 6, 9, 12, 15]).to_string(), "3 x");
+        let triangular = fit(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(triangular.degree(), 2);
+        assert_eq!(
+            triangular.coefficients(),
+            [rational(1, 1), rational(3, 2), rational(1, 2)]
+        );
+        assert_eq!(triangular.to_string(), "1/2 x^2 + 3/2 x + 1");
+        assert_eq!(
+            fit(&[10, 13, 16, 21, 30, 45]).to_string(),
+            "1/3 x^3 - x^2 + 11/3 x + 10"
+        );
+        assert_eq!(fit(&[0, 0, 0]).to_string(), "0");
+        assert_eq!(fit(&[-4, -4]).to_string(), "-4");
+    }
+
+    #[test]
+    fn it_reproduces_every_reading() {
+        let input = crate::generate::generate(&mut aoc_common::Rng::new(25), 50);
+        for line in input.lines() {
+            let values: Vec<i64> = crate::parse_values(line, 0).unwrap();
+            let residuals = fit(&values).residuals(&values);
+            assert!(residuals.iter().all(Zero::is_zero), "{}", line);
+        }
+    }
+
+    #[test]
+    fn it_fits_readings_the_table_rejects() {
+        assert!(DifferenceTable::new(&[1, 2, 4, 8]).is_err());
+        let powers = Polynomial::through(&[1, 2, 4, 8]);
+        assert_eq!(powers.to_string(), "1/6 x^3 + 5/6 x + 1");
+        assert_eq!(Polynomial::through(&[7]).to_string(), "7");
+        assert_eq!(Polynomial::through(&[0, 3, 6, 9]), fit(&[0, 3, 6, 9]));
+    }
+}
@@ -0,0 +1,470 @@
+use num_bigint::BigInt;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use std::fmt;
+
+/// Why a sequence of readings cannot be extrapolated.
+#[derive(Debug, Eq, PartialEq)]
+pub enum SequenceError {
+    /// There are no readings to extrapolate from.
+    Empty,
+    /// The differences only become constant once a row is down to its last
+    /// value, which leaves nothing to show how they carry on.
+    NeverConstant,
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceError::Empty => write!(f, "Expected at least one reading"),
+            SequenceError::NeverConstant => {
+                write!(f, "The differences never become constant")
+            }
+        }
+    }
+}
+
+/// The integer types the readings are extrapolated in: `i64` first, with
+/// every sum and difference checked, then big integers when that overflows.
+trait Number: Clone + Eq + From<i64> + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv {}
+
+impl<T> Number for T where
+    T: Clone + Eq + From<i64> + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv
+{
+}
+
+/// Why working out the rows of a table stopped short.
+enum Stop {
+    Sequence(SequenceError),
+    Overflow,
+}
+
+impl From<SequenceError> for Stop {
+    fn from(error: SequenceError) -> Self {
+        Stop::Sequence(error)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Forward,
+    Backward,
+}
+
+/// The rows of differences of a sequence of readings: the readings
+/// themselves, then the differences between each pair of neighbouring
+/// readings, then the differences between those, down to the first row that
+/// is constant, meaning every difference in it would be zero.
+///
+/// A single reading is taken to be constant, but in a longer sequence the
+/// constant row has to have at least two values in it to show that it is.
+///
+/// The differences of a high degree sequence grow much faster than its
+/// readings, so the table is kept in big integers instead whenever a row, or
+/// the reading either side of the sequence, would overflow an `i64`.
+pub struct DifferenceTable {
+    rows: Rows,
+}
+
+enum Rows {
+    Narrow(Vec<Vec<i64>>),
+    Wide(Vec<Vec<BigInt>>),
+}
+
+fn differences<T: Number>(values: &[T]) -> Option<Vec<T>> {
+    values
+        .windows(2)
+        .map(|pair| pair[1].checked_sub(&pair[0]))
+        .collect()
+}
+
+fn is_constant<T: Number>(values: &[T]) -> bool {
+    values.windows(2).all(|pair| pair[0] == pair[1])
+}
+
+fn difference_rows<T: Number>(values: Vec<T>) -> Result<Vec<Vec<T>>, Stop> {
+    let mut rows = vec![values];
+    while !is_constant(rows.last().unwrap()) {
+        let changes = differences(rows.last().unwrap()).ok_or(Stop::Overflow)?;
+        if changes.len() == 1 {
+            return Err(SequenceError::NeverConstant.into());
+        }
+        rows.push(changes);
+    }
+    Ok(rows)
+}
+
+/// Extends every row of a table by `steps` values, starting from the
+/// constant row, and returns the values added to the sequence itself, or
+/// `None` if any of them overflow.
+fn extend<T: Number>(rows: &[Vec<T>], steps: usize, direction: Direction) -> Option<Vec<T>> {
+    let mut edges: Vec<T> = rows
+        .iter()
+        .map(|row| match direction {
+            Direction::Forward => row[row.len() - 1].clone(),
+            Direction::Backward => row[0].clone(),
+        })
+        .collect();
+    let constant = rows.len() - 1;
+
+    (0..steps)
+        .map(|_| {
+            for row in (0..constant).rev() {
+                edges[row] = match direction {
+                    Direction::Forward => edges[row].checked_add(&edges[row + 1])?,
+                    Direction::Backward => edges[row].checked_sub(&edges[row + 1])?,
+                };
+            }
+            Some(edges[0].clone())
+        })
+        .collect()
+}
+
+fn widen(rows: &[Vec<i64>]) -> Vec<Vec<BigInt>> {
+    rows.iter()
+        .map(|row| row.iter().map(|&value| BigInt::from(value)).collect())
+        .collect()
+}
+
+const BIG_INTEGERS_OVERFLOWED: &str = "Big integers cannot overflow";
+
+impl DifferenceTable {
+    pub fn new(values: &[i64]) -> Result<DifferenceTable, SequenceError> {
+        if values.is_empty() {
+            return Err(SequenceError::Empty);
+        }
+
+        let rows = match difference_rows(values.to_vec()) {
+            Ok(rows)
+                if extend(&rows, 1, Direction::Forward).is_some()
+                    && extend(&rows, 1, Direction::Backward).is_some() =>
+            {
+                Rows::Narrow(rows)
+            }
+            Ok(rows) => Rows::Wide(widen(&rows)),
+            Err(Stop::Sequence(error)) => return Err(error),
+            Err(Stop::Overflow) => {
+                match difference_rows(values.iter().map(|&value| BigInt::from(value)).collect()) {
+                    Ok(rows) => Rows::Wide(rows),
+                    Err(Stop::Sequence(error)) => return Err(error),
+                    Err(Stop::Overflow) => unreachable!("{}", BIG_INTEGERS_OVERFLOWED),
+                }
+            }
+        };
+        Ok(DifferenceTable { rows })
+    }
+
+    /// Whether the table had to be kept in big integers because its
+    /// differences, or the readings either side of the sequence, overflow an
+    /// `i64`.
+    pub fn needs_big_integers(&self) -> bool {
+        matches!(self.rows, Rows::Wide(_))
+    }
+
+    /// How many rows of differences there are below the readings, which is
+    /// the degree of the polynomial they follow.
+    pub fn degree(&self) -> usize {
+        match &self.rows {
+            Rows::Narrow(rows) => rows.len() - 1,
+            Rows::Wide(rows) => rows.len() - 1,
+        }
+    }
+
+    /// The first value of every row, from the first reading down to the
+    /// constant row.
+    pub fn leading_differences(&self) -> Vec<BigInt> {
+        match &self.rows {
+            Rows::Narrow(rows) => rows.iter().map(|row| BigInt::from(row[0])).collect(),
+            Rows::Wide(rows) => rows.iter().map(|row| row[0].clone()).collect(),
+        }
+    }
+
+    /// Predicts `steps` readings, falling back to big integers if they
+    /// overflow.
+    fn predict(&self, steps: usize, direction: Direction) -> Vec<BigInt> {
+        let predictions = match &self.rows {
+            Rows::Narrow(rows) => match extend(rows, steps, direction) {
+                Some(predictions) => return predictions.into_iter().map(BigInt::from).collect(),
+                None => extend(&widen(rows), steps, direction),
+            },
+            Rows::Wide(rows) => extend(rows, steps, direction),
+        };
+        predictions.expect(BIG_INTEGERS_OVERFLOWED)
+    }
+
+    /// The next `steps` readings after the end of the sequence.
+    pub fn forward(&self, steps: usize) -> Vec<BigInt> {
+        self.predict(steps, Direction::Forward)
+    }
+
+    /// The `steps` readings before the start of the sequence, from the
+    /// nearest to the furthest.
+    pub fn backward(&self, steps: usize) -> Vec<BigInt> {
+        self.predict(steps, Direction::Backward)
+    }
+
+    /// The reading that would follow the sequence.
+    pub fn next_value(&self) -> BigInt {
+        self.forward(1).remove(0)
+    }
+
+    /// The reading that would come before the sequence.
+    pub fn previous_value(&self) -> BigInt {
+        self.backward(1).remove(0)
+    }
+}
+
+/// Differences a single row in place until it is constant, keeping none of
+/// the rows above it.
+fn becomes_constant<T: Number>(mut row: Vec<T>) -> Result<(), Stop> {
+    while !is_constant(&row) {
+        for i in 1..row.len() {
+            row[i - 1] = row[i].checked_sub(&row[i - 1]).ok_or(Stop::Overflow)?;
+        }
+        row.pop();
+        if row.len() == 1 {
+            return Err(SequenceError::NeverConstant.into());
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a sequence can be extrapolated, by the same rule as
+/// `DifferenceTable::new` but without keeping every row of differences, for
+/// solvers that extrapolate without the table.
+pub fn check(values: &[i64]) -> Result<(), SequenceError> {
+    if values.is_empty() {
+        return Err(SequenceError::Empty);
+    }
+
+    match becomes_constant(values.to_vec()) {
+        Ok(()) => Ok(()),
+        Err(Stop::Sequence(error)) => Err(error),
+        Err(Stop::Overflow) => {
+            match becomes_constant(values.iter().map(|&value| BigInt::from(value)).collect()) {
+                Ok(()) => Ok(()),
+                Err(Stop::Sequence(error)) => Err(error),
+                Err(Stop::Overflow) => unreachable!("{}", BIG_INTEGERS_OVERFLOWED),
+            }
+        }
+    }
+}
+
+/// The binomial coefficients `n` choose 0 up to `n` choose `n`, worked out
+/// one from the last as they are needed, or `None` from the first that
+/// overflows.
+fn binomials<T: Number>(n: usize) -> impl Iterator<Item = Option<T>> {
+    let mut coefficient = Some(T::from(1));
+    (0..=n).map(move |k| {
+        let current = coefficient.clone();
+        coefficient = current
+            .as_ref()
+            .and_then(|c| c.checked_mul(&T::from((n - k) as i64)))
+            .and_then(|c| c.checked_div(&T::from(k as i64 + 1)));
+        current
+    })
+}
+
+/// Sums the readings, each multiplied by its weight and added when
+/// `positive` says so for its position or subtracted otherwise.
+fn weighted_sum<T: Number>(
+    values: &[i64],
+    weights: impl Iterator<Item = Option<T>>,
+    positive: impl Fn(usize) -> bool,
+) -> Option<T> {
+    values
+        .iter()
+        .zip(weights)
+        .enumerate()
+        .try_fold(T::from(0), |sum, (k, (&value, weight))| {
+            let term = weight?.checked_mul(&T::from(value))?;
+            if positive(k) {
+                sum.checked_add(&term)
+            } else {
+                sum.checked_sub(&term)
+            }
+        })
+}
+
+/// The reading that would follow a non-empty sequence, worked out straight
+/// from the readings rather than from a table of differences. Taking the
+/// sequence's `n`th differences to be zero, Newton's forward difference
+/// formula makes the next reading a sum of the readings weighted by
+/// alternating binomial coefficients, which needs no allocation however
+/// long the sequence is. The weighted sum is worked out in 128 bits, or in
+/// big integers if that overflows.
+///
+/// This agrees with the difference table on every sequence `check`
+/// accepts. Sequences it rejects still get a value, the next reading of the
+/// lowest degree polynomial through them, so check them first to agree with
+/// the table on which sequences can be extrapolated at all.
+pub fn next_by_weights(values: &[i64]) -> BigInt {
+    assert!(!values.is_empty(), "Cannot extrapolate an empty sequence");
+    let n = values.len();
+    let positive = |k: usize| (n - 1 - k).is_multiple_of(2);
+    match weighted_sum::<i128>(values, binomials(n), positive) {
+        Some(next) => BigInt::from(next),
+        None => weighted_sum(values, binomials(n), positive).expect(BIG_INTEGERS_OVERFLOWED),
+    }
+}
+
+/// The reading that would come before a non-empty sequence, worked out the
+/// same way as `next_by_weights`.
+pub fn previous_by_weights(values: &[i64]) -> BigInt {
+    assert!(!values.is_empty(), "Cannot extrapolate an empty sequence");
+    let n = values.len();
+    let positive = |k: usize| k.is_multiple_of(2);
+    match weighted_sum::<i128>(values, binomials(n).skip(1), positive) {
+        Some(previous) => BigInt::from(previous),
+        None => {
+            weighted_sum(values, binomials(n).skip(1), positive).expect(BIG_INTEGERS_OVERFLOWED)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(values: &[i64]) -> DifferenceTable {
+        DifferenceTable::new(values).unwrap()
+    }
+
+    fn big(values: &[i64]) -> Vec<BigInt> {
+        values.iter().map(|&value| BigInt::from(value)).collect()
+    }
+
+    #[test]
+    fn it_predicts_several_steps_either_way() {
+        let table = table(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(table.forward(3), big(&[68, 101, 146]));
+        assert_eq!(table.backward(3), big(&[5, -4, -19]));
+    }
+
+    #[test]
+    fn it_predicts_one_step_either_way() {
+        assert_eq!(table(&[1, 3, 6, 10, 15, 21]).next_value(), BigInt::from(28));
+        assert_eq!(
+            table(&[1, 3, 6, 10, 15, 21]).previous_value(),
+            BigInt::from(0)
+        );
+        assert_eq!(
+            table(&[0, 3, 6, 9, 12, 15]).previous_value(),
+            BigInt::from(-3)
+        );
+    }
+
+    #[test]
+    fn it_keeps_going_past_differences_that_only_sum_to_zero() {
+        // The first differences are 3 1 -1 -3, which sum to zero without
+        // being constant
+        let table = table(&[0, 3, 4, 3, 0]);
+        assert_eq!(table.next_value(), BigInt::from(-5));
+        assert_eq!(table.previous_value(), BigInt::from(-5));
+    }
+
+    #[test]
+    fn it_treats_a_single_reading_as_constant() {
+        assert_eq!(table(&[7]).forward(2), big(&[7, 7]));
+        assert_eq!(table(&[7]).backward(2), big(&[7, 7]));
+    }
+
+    #[test]
+    fn it_rejects_sequences_it_cannot_extrapolate() {
+        assert_eq!(DifferenceTable::new(&[]).err(), Some(SequenceError::Empty));
+        assert_eq!(
+            DifferenceTable::new(&[1, 2, 4]).err(),
+            Some(SequenceError::NeverConstant)
+        );
+        assert_eq!(
+            DifferenceTable::new(&[0, 1, 0]).err(),
+            Some(SequenceError::NeverConstant)
+        );
+    }
+
+    #[test]
+    fn it_weights_readings_by_binomial_coefficients() {
+        assert_eq!(
+            binomials(4).collect::<Option<Vec<i128>>>(),
+            Some(vec![1, 4, 6, 4, 1])
+        );
+        assert!(binomials::<i64>(70).any(|binomial| binomial.is_none()));
+        assert_eq!(next_by_weights(&[10, 13, 16, 21, 30, 45]), BigInt::from(68));
+        assert_eq!(
+            previous_by_weights(&[10, 13, 16, 21, 30, 45]),
+            BigInt::from(5)
+        );
+        assert_eq!(next_by_weights(&[7]), BigInt::from(7));
+        assert_eq!(previous_by_weights(&[7]), BigInt::from(7));
+        // Too short for the table, but a parabola through three points
+        assert_eq!(next_by_weights(&[1, 2, 4]), BigInt::from(7));
+    }
+
+    #[test]
+    fn it_extrapolates_by_weights_as_the_table_does() {
+        let input = crate::generate::generate(&mut aoc_common::Rng::new(23), 200);
+        for line in input.lines() {
+            let values: Vec<i64> = crate::parse_values(line, 0).unwrap();
+            let table = table(&values);
+            assert_eq!(next_by_weights(&values), table.next_value(), "{}", line);
+            assert_eq!(
+                previous_by_weights(&values),
+                table.previous_value(),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn it_falls_back_to_big_integers_when_the_differences_overflow() {
+        // The parabola (2k - 3)² 2⁶⁰ - 6 × 2⁶⁰, whose first differences
+        // reach 2⁶³
+        let values = [3 << 60, -5 << 60, -5 << 60, 3 << 60];
+        let table = table(&values);
+        assert!(table.needs_big_integers());
+        let expected = BigInt::from(19) << 60;
+        assert_eq!(table.next_value(), expected);
+        assert_eq!(table.previous_value(), expected);
+        assert_eq!(next_by_weights(&values), expected);
+        assert_eq!(previous_by_weights(&values), expected);
+    }
+
+    #[test]
+    fn it_falls_back_to_big_integers_when_a_prediction_overflows() {
+        let near_the_top = table(&[i64::MAX - 2, i64::MAX - 1, i64::MAX]);
+        assert!(near_the_top.needs_big_integers());
+        assert_eq!(near_the_top.next_value(), BigInt::from(i64::MAX) + 1);
+
+        let counting = table(&[1, 2, 3]);
+        assert!(!counting.needs_big_integers());
+        assert_eq!(counting.forward(2), big(&[4, 5]));
+    }
+
+    #[test]
+    fn it_checks_sequences_as_the_table_does() {
+        // Every sequence of up to five readings between -2 and 2
+        let mut sequences: Vec<Vec<i64>> = vec![vec![]];
+        for length in 1..=5 {
+            let shorter: Vec<Vec<i64>> = sequences
+                .iter()
+                .filter(|values| values.len() == length - 1)
+                .cloned()
+                .collect();
+            for values in shorter {
+                for value in -2..=2 {
+                    sequences.push([values.clone(), vec![value]].concat());
+                }
+            }
+        }
+        for values in sequences {
+            let table = DifferenceTable::new(&values).map(|_| ());
+            assert_eq!(check(&values), table, "{:?}", values);
+        }
+
+        assert_eq!(check(&[3 << 60, -5 << 60, -5 << 60, 3 << 60]), Ok(()));
+        assert_eq!(
+            check(&[i64::MIN, i64::MAX, i64::MIN]),
+            Err(SequenceError::NeverConstant)
+        );
+    }
+}
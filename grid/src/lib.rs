@@ -0,0 +1,285 @@
+use aoc_common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in a single `Vec`, indexed by `(x, y)`
+/// with the origin in the top left corner.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid size does not match");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, one row per line. Every row must have
+    /// the same width.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+        let mut cells: Vec<T> = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell =
+                    parse_cell(c).ok_or_else(|| ParseError::new(y, x, c, "Invalid character"))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        y,
+                        row_width.min(width),
+                        line.chars().skip(width).collect::<String>(),
+                        format!("Expected a row of width {}", width),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every cell along with its `(x, y)` position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The in-bounds positions directly north, south, west and east of a cell.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let x = x.checked_add_signed(dx)?;
+                let y = y.checked_add_signed(dy)?;
+                (x < width && y < height).then_some((x, y))
+            })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Formats the grid as text, one character per cell.
+    pub fn render(&self, mut to_char: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut to_char));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Swaps rows and columns, so that column `x` becomes row `x`.
+    pub fn transpose(&self) -> Grid<T> {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates a quarter turn clockwise, so that the west edge becomes the
+    /// north edge.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates a quarter turn anticlockwise, so that the east edge becomes the
+    /// north edge.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Builds a new grid where each cell `(x, y)` is copied from the cell of
+    /// this grid at `source(x, y)`.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+        Grid::new(width, height, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("Position out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("Position out of bounds")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn it_parses_a_char_map() {
+        let grid = parse_chars("ab\ncd\nef\n");
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'b');
+        assert_eq!(grid[(0, 2)], 'e');
+        assert_eq!(grid.get(2, 0), None);
+    }
+
+    #[test]
+    fn it_reports_invalid_characters() {
+        let error = Grid::parse("..\n.x", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 1, "x", "Invalid character"));
+    }
+
+    #[test]
+    fn it_reports_ragged_rows() {
+        let error = Grid::parse("...\n....", Some).unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn it_views_rows_and_columns() {
+        let grid = parse_chars("abc\ndef\n");
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn it_finds_neighbours_within_bounds() {
+        let grid = parse_chars("abc\ndef\n");
+        let neighbours: Vec<(usize, usize)> = grid.neighbours(0, 0).collect();
+        assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours(1, 1).count(), 3);
+    }
+
+    #[test]
+    fn it_transposes() {
+        let grid = parse_chars("abc\ndef\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn it_rotates() {
+        let grid = parse_chars("abc\ndef\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn it_renders_back_to_text() {
+        let grid = Grid::parse("#.\n.#\n", |c| Some(c == '#')).unwrap();
+        assert_eq!(
+            grid.render(|&rock| if rock { '#' } else { '.' }),
+            "#.\n.#\n"
+        );
+    }
+}